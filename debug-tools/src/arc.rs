@@ -0,0 +1,109 @@
+use embedded_graphics::{
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::{Arc, PrimitiveStyle, Styled},
+};
+use framework::{draw, geometry::Outline, prelude::*};
+use std::convert::Infallible;
+
+pub struct ArcDebug {
+    center: Point,
+    diameter: u32,
+    angle_start: Angle,
+    angle_sweep: Angle,
+    stroke_width: u32,
+    show_bounding_box: bool,
+}
+
+impl ArcDebug {
+    fn styled_arc(&self) -> Styled<Arc, PrimitiveStyle<Rgb888>> {
+        let arc = Arc::with_center(
+            self.center,
+            self.diameter,
+            self.angle_start,
+            self.angle_sweep,
+        );
+
+        let style = PrimitiveStyle::with_stroke(Rgb888::CSS_SPRING_GREEN, self.stroke_width);
+
+        arc.into_styled(style)
+    }
+}
+
+impl App for ArcDebug {
+    type Color = Rgb888;
+    const DISPLAY_SIZE: Size = Size::new(256, 256);
+
+    fn new() -> Self {
+        Self {
+            center: Point::new(128, 128),
+            diameter: 50,
+            angle_start: 0.0.deg(),
+            angle_sweep: 30.0.deg(),
+            stroke_width: 1,
+            show_bounding_box: false,
+        }
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("center", &mut self.center),
+            Parameter::new("diameter", &mut self.diameter),
+            Parameter::new("start", &mut self.angle_start)
+                .range(0..=360)
+                .wrap(),
+            Parameter::new("sweep", &mut self.angle_sweep).range(-360..=360),
+            Parameter::new("stroke", &mut self.stroke_width),
+            Parameter::new("show BB", &mut self.show_bounding_box),
+        ]
+    }
+
+    fn ideal_geometry(&self) -> Vec<Outline> {
+        self.styled_arc().ideal_geometry()
+    }
+
+    fn status(&self) -> Option<String> {
+        self.show_bounding_box
+            .then(|| draw::BoundingBoxes::new(&self.styled_arc()).to_string())
+    }
+
+    fn check_invariants(&self) -> Result<(), String> {
+        draw::check_bounding_box(&self.styled_arc())
+    }
+
+    fn draw_underlay<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        if self.show_bounding_box {
+            draw::bounding_box(&self.styled_arc(), display)?;
+        }
+
+        Ok(())
+    }
+
+    fn draw<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        self.styled_arc().draw(display)
+    }
+
+    fn draw_overlay<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        if self.show_bounding_box {
+            draw::point(self.center, Rgb888::CSS_LIGHT_SKY_BLUE, display);
+        }
+
+        Ok(())
+    }
+
+    fn draw_reference<D>(&self, display: &mut D) -> Option<Result<(), Infallible>>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        Some(self.styled_arc().draw_reference(display))
+    }
+}
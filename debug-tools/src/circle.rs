@@ -0,0 +1,100 @@
+use embedded_graphics::{
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::{Circle, PrimitiveStyle, PrimitiveStyleBuilder, Styled},
+};
+use framework::{draw, geometry::Outline, prelude::*};
+use std::convert::Infallible;
+
+pub struct CircleDebug {
+    center: Point,
+    diameter: u32,
+    stroke_width: u32,
+    show_bounding_box: bool,
+}
+
+impl CircleDebug {
+    fn styled_circle(&self) -> Styled<Circle, PrimitiveStyle<Rgb888>> {
+        let circle = Circle::with_center(self.center, self.diameter);
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(Rgb888::CSS_SPRING_GREEN)
+            .stroke_width(self.stroke_width)
+            .fill_color(Rgb888::CSS_DARK_SEA_GREEN)
+            .build();
+
+        circle.into_styled(style)
+    }
+}
+
+impl App for CircleDebug {
+    type Color = Rgb888;
+    const DISPLAY_SIZE: Size = Size::new(256, 256);
+
+    fn new() -> Self {
+        Self {
+            center: Point::new(128, 128),
+            diameter: 50,
+            stroke_width: 1,
+            show_bounding_box: false,
+        }
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("center", &mut self.center),
+            Parameter::new("diameter", &mut self.diameter),
+            Parameter::new("stroke", &mut self.stroke_width),
+            Parameter::new("show BB", &mut self.show_bounding_box),
+        ]
+    }
+
+    fn ideal_geometry(&self) -> Vec<Outline> {
+        self.styled_circle().ideal_geometry()
+    }
+
+    fn status(&self) -> Option<String> {
+        self.show_bounding_box
+            .then(|| draw::BoundingBoxes::new(&self.styled_circle()).to_string())
+    }
+
+    fn check_invariants(&self) -> Result<(), String> {
+        draw::check_bounding_box(&self.styled_circle())
+    }
+
+    fn draw_underlay<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        if self.show_bounding_box {
+            draw::bounding_box(&self.styled_circle(), display)?;
+        }
+
+        Ok(())
+    }
+
+    fn draw<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        self.styled_circle().draw(display)
+    }
+
+    fn draw_overlay<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        if self.show_bounding_box {
+            draw::point(self.center, Rgb888::CSS_LIGHT_SKY_BLUE, display);
+        }
+
+        Ok(())
+    }
+
+    fn draw_reference<D>(&self, display: &mut D) -> Option<Result<(), Infallible>>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        Some(self.styled_circle().draw_reference(display))
+    }
+}
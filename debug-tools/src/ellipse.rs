@@ -0,0 +1,98 @@
+use embedded_graphics::{
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::{
+        Ellipse, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, StrokeAlignment, Styled,
+    },
+};
+use framework::{draw, geometry::Outline, prelude::*, STROKE_ALIGNMENTS};
+use std::convert::Infallible;
+
+pub struct EllipseDebug {
+    bounding_box: Rectangle,
+    stroke_width: u32,
+    stroke_alignment: StrokeAlignment,
+    show_bounding_box: bool,
+}
+
+impl EllipseDebug {
+    fn styled_ellipse(&self) -> Styled<Ellipse, PrimitiveStyle<Rgb888>> {
+        let ellipse = Ellipse::new(self.bounding_box.top_left, self.bounding_box.size);
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(Rgb888::RED)
+            .stroke_width(self.stroke_width)
+            .stroke_alignment(self.stroke_alignment)
+            .fill_color(Rgb888::GREEN)
+            .build();
+
+        ellipse.into_styled(style)
+    }
+}
+
+impl App for EllipseDebug {
+    type Color = Rgb888;
+    const DISPLAY_SIZE: Size = Size::new(200, 200);
+
+    fn new() -> Self {
+        Self {
+            bounding_box: Rectangle::with_corners(Point::new(50, 50), Point::new(100, 100)),
+            stroke_width: 5,
+            stroke_alignment: StrokeAlignment::Center,
+            show_bounding_box: false,
+        }
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("rectangle", &mut self.bounding_box),
+            Parameter::new("stroke", &mut self.stroke_width),
+            Parameter::choice("alignment", &mut self.stroke_alignment, &STROKE_ALIGNMENTS),
+            Parameter::new("show BB", &mut self.show_bounding_box),
+        ]
+    }
+
+    fn ideal_geometry(&self) -> Vec<Outline> {
+        self.styled_ellipse().ideal_geometry()
+    }
+
+    fn status(&self) -> Option<String> {
+        let mut status = format!("S: {}\n{:?}", self.stroke_width, self.bounding_box.size);
+
+        if self.show_bounding_box {
+            status.push('\n');
+            status.push_str(&draw::BoundingBoxes::new(&self.styled_ellipse()).to_string());
+        }
+
+        Some(status)
+    }
+
+    fn check_invariants(&self) -> Result<(), String> {
+        draw::check_bounding_box(&self.styled_ellipse())
+    }
+
+    fn draw_underlay<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        if self.show_bounding_box {
+            draw::bounding_box(&self.styled_ellipse(), display)?;
+        }
+
+        Ok(())
+    }
+
+    fn draw<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        self.styled_ellipse().draw(display)
+    }
+
+    fn draw_reference<D>(&self, display: &mut D) -> Option<Result<(), Infallible>>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        Some(self.styled_ellipse().draw_reference(display))
+    }
+}
@@ -0,0 +1,16 @@
+//! Debuggers which are included in the `debug-tools` binary.
+//!
+//! The debuggers are exported by this library to make it possible to use them in tests.
+
+pub mod arc;
+pub mod circle;
+pub mod ellipse;
+pub mod line;
+// Contains code copied from e-g, which isn't entirely used.
+#[allow(dead_code)]
+pub mod line_intersection;
+pub mod polyline;
+pub mod rectangle_intersection;
+pub mod rounded_rectangle;
+pub mod sector;
+pub mod text_decoration;
@@ -0,0 +1,61 @@
+use embedded_graphics::{
+    pixelcolor::Rgb565,
+    prelude::*,
+    primitives::{Line, PrimitiveStyle, Styled},
+};
+use framework::{geometry::Outline, prelude::*};
+use std::convert::Infallible;
+
+pub struct LineDebug {
+    start: Point,
+    end: Point,
+    stroke_width: u32,
+}
+
+impl LineDebug {
+    fn styled_line(&self) -> Styled<Line, PrimitiveStyle<Rgb565>> {
+        Line::new(self.start, self.end).into_styled(PrimitiveStyle::with_stroke(
+            Rgb565::GREEN,
+            self.stroke_width,
+        ))
+    }
+}
+
+impl App for LineDebug {
+    type Color = Rgb565;
+    const DISPLAY_SIZE: Size = Size::new(256, 256);
+
+    fn new() -> Self {
+        Self {
+            start: Point::new(128, 128),
+            end: Point::new(150, 170),
+            stroke_width: 1,
+        }
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("start", &mut self.start),
+            Parameter::new("end", &mut self.end),
+            Parameter::new("stroke", &mut self.stroke_width),
+        ]
+    }
+
+    fn ideal_geometry(&self) -> Vec<Outline> {
+        self.styled_line().ideal_geometry()
+    }
+
+    fn draw<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        self.styled_line().draw(display)
+    }
+
+    fn draw_reference<D>(&self, display: &mut D) -> Option<Result<(), Infallible>>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        Some(self.styled_line().draw_reference(display))
+    }
+}
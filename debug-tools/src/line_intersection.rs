@@ -7,10 +7,10 @@ use embedded_graphics::{
     primitives::PrimitiveStyle,
     text::Text,
 };
-use embedded_graphics_simulator::{OutputSettingsBuilder, SimulatorDisplay, Window};
 use framework::{draw, prelude::*};
+use std::convert::Infallible;
 
-struct LineDebug {
+pub struct LineDebug {
     l1_start: Point,
     l1_end: Point,
     l2_start: Point,
@@ -39,10 +39,10 @@ impl App for LineDebug {
         ]
     }
 
-    fn draw(
-        &self,
-        display: &mut SimulatorDisplay<Self::Color>,
-    ) -> Result<(), std::convert::Infallible> {
+    fn draw<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
         let line1 = Line::new(self.l1_start, self.l1_end);
         let line2 = Line::new(self.l2_start, self.l2_end);
 
@@ -88,13 +88,6 @@ impl App for LineDebug {
     }
 }
 
-fn main() {
-    let settings = OutputSettingsBuilder::new().scale(3).build();
-    let window = Window::new("Line intersection debugger", &settings);
-
-    LineDebug::run(window);
-}
-
 // -------------------------------------------------------------------------------------------------
 // Copied code from e-g, because these types aren't public
 // -------------------------------------------------------------------------------------------------
@@ -1,3 +1,44 @@
+use debug_tools::{
+    arc, circle, ellipse, line, line_intersection, polyline, rectangle_intersection,
+    rounded_rectangle, sector, text_decoration,
+};
+use embedded_graphics_simulator::OutputSettingsBuilder;
+use framework::prelude::*;
+
 fn main() {
-    println!("Use 'cargo run --example NAME' to run the debug tools");
+    let settings = OutputSettingsBuilder::new().scale(3).build();
+    let text_settings = OutputSettingsBuilder::new().scale(2).build();
+    let spaced_settings = OutputSettingsBuilder::new()
+        .scale(2)
+        .pixel_spacing(1)
+        .build();
+
+    Launcher::new()
+        .add::<arc::ArcDebug>("arc", "Arc debugger", &settings)
+        .add::<circle::CircleDebug>("circle", "Circle debugger", &settings)
+        .add::<ellipse::EllipseDebug>("ellipse", "Ellipse debugger", &spaced_settings)
+        .add::<line::LineDebug>("line", "Line debugger", &settings)
+        .add::<line_intersection::LineDebug>(
+            "line-intersection",
+            "Line intersection debugger",
+            &settings,
+        )
+        .add::<polyline::PolylineDebug>("polyline", "Polyline debugger", &settings)
+        .add::<rectangle_intersection::RectangleIntersection>(
+            "rectangle-intersection",
+            "Rectangle intersection",
+            &settings,
+        )
+        .add::<rounded_rectangle::RoundedRectangleDebug>(
+            "rounded-rectangle",
+            "Rounded rectangle debugger",
+            &spaced_settings,
+        )
+        .add::<sector::SectorDebug>("sector", "Sector debugger", &settings)
+        .add::<text_decoration::TextDecoration>(
+            "text-decoration",
+            "Builtin font decoration debugger",
+            &text_settings,
+        )
+        .run();
 }
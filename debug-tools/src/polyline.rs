@@ -3,10 +3,10 @@ use embedded_graphics::{
     prelude::*,
     primitives::{Polyline, PrimitiveStyle},
 };
-use embedded_graphics_simulator::{OutputSettingsBuilder, SimulatorDisplay, Window};
 use framework::prelude::*;
+use std::convert::Infallible;
 
-struct PolylineDebug {
+pub struct PolylineDebug {
     points: u32,
     p1: Point,
     p2: Point,
@@ -34,7 +34,7 @@ impl App for PolylineDebug {
 
     fn parameters(&mut self) -> Vec<Parameter> {
         vec![
-            Parameter::new("points", &mut self.points),
+            Parameter::new("points", &mut self.points).range(0..=5),
             Parameter::new("p1", &mut self.p1),
             Parameter::new("p2", &mut self.p2),
             Parameter::new("p3", &mut self.p3),
@@ -44,10 +44,10 @@ impl App for PolylineDebug {
         ]
     }
 
-    fn draw(
-        &self,
-        display: &mut SimulatorDisplay<Self::Color>,
-    ) -> Result<(), std::convert::Infallible> {
+    fn draw<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
         let points = [self.p1, self.p2, self.p3, self.p4, self.p5];
         let points = &points[0..(self.points as usize).min(points.len())];
 
@@ -59,10 +59,3 @@ impl App for PolylineDebug {
             .draw(display)
     }
 }
-
-fn main() {
-    let settings = OutputSettingsBuilder::new().scale(3).build();
-    let window = Window::new("Polyline debugger", &settings);
-
-    PolylineDebug::run(window);
-}
@@ -3,12 +3,11 @@ use embedded_graphics::{
     prelude::*,
     primitives::{PrimitiveStyle, Rectangle},
 };
-use embedded_graphics_simulator::{OutputSettingsBuilder, SimulatorDisplay, Window};
 use framework::prelude::*;
+use std::convert::Infallible;
 
-struct RectangleIntersection {
-    top_left: Point,
-    bottom_right: Point,
+pub struct RectangleIntersection {
+    rectangle: Rectangle,
 }
 
 impl App for RectangleIntersection {
@@ -17,24 +16,20 @@ impl App for RectangleIntersection {
 
     fn new() -> Self {
         Self {
-            top_left: Point::new(80, 80),
-            bottom_right: Point::new(150, 150),
+            rectangle: Rectangle::with_corners(Point::new(80, 80), Point::new(150, 150)),
         }
     }
 
     fn parameters(&mut self) -> Vec<Parameter> {
-        vec![
-            Parameter::new("top-left", &mut self.top_left),
-            Parameter::new("bottom-right", &mut self.bottom_right),
-        ]
+        vec![Parameter::new("rectangle", &mut self.rectangle)]
     }
 
-    fn draw(
-        &self,
-        display: &mut SimulatorDisplay<Self::Color>,
-    ) -> Result<(), std::convert::Infallible> {
+    fn draw<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
         let base_rectangle = Rectangle::with_corners(Point::new(20, 20), Point::new(100, 100));
-        let moving_rectangle = Rectangle::with_corners(self.top_left, self.bottom_right);
+        let moving_rectangle = self.rectangle;
 
         base_rectangle
             .into_styled(PrimitiveStyle::with_fill(Rgb888::RED))
@@ -53,10 +48,3 @@ impl App for RectangleIntersection {
         Ok(())
     }
 }
-
-fn main() {
-    let settings = OutputSettingsBuilder::new().scale(3).build();
-    let window = Window::new("Rectangle intersection", &settings);
-
-    RectangleIntersection::run(window);
-}
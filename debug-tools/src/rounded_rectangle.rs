@@ -0,0 +1,92 @@
+use embedded_graphics::{
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::{
+        PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, RoundedRectangle, StrokeAlignment, Styled,
+    },
+};
+use framework::{draw, prelude::*, STROKE_ALIGNMENTS};
+use std::convert::Infallible;
+
+pub struct RoundedRectangleDebug {
+    rectangle: Rectangle,
+    corner_radius: Size,
+    stroke_width: u32,
+    stroke_alignment: StrokeAlignment,
+    show_bounding_box: bool,
+}
+
+impl RoundedRectangleDebug {
+    fn styled_rounded_rectangle(&self) -> Styled<RoundedRectangle, PrimitiveStyle<Rgb888>> {
+        let rounded_rectangle =
+            RoundedRectangle::with_equal_corners(self.rectangle, self.corner_radius);
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(Rgb888::RED)
+            .stroke_width(self.stroke_width)
+            .stroke_alignment(self.stroke_alignment)
+            .fill_color(Rgb888::GREEN)
+            .build();
+
+        rounded_rectangle.into_styled(style)
+    }
+}
+
+impl App for RoundedRectangleDebug {
+    type Color = Rgb888;
+    const DISPLAY_SIZE: Size = Size::new(200, 200);
+
+    fn new() -> Self {
+        Self {
+            rectangle: Rectangle::with_corners(Point::new(20, 20), Point::new(100, 100)),
+            corner_radius: Size::new(20, 20),
+            stroke_width: 5,
+            stroke_alignment: StrokeAlignment::Center,
+            show_bounding_box: false,
+        }
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("rectangle", &mut self.rectangle),
+            Parameter::new("radius", &mut self.corner_radius),
+            Parameter::new("stroke", &mut self.stroke_width),
+            Parameter::choice("alignment", &mut self.stroke_alignment, &STROKE_ALIGNMENTS),
+            Parameter::new("show BB", &mut self.show_bounding_box),
+        ]
+    }
+
+    fn status(&self) -> Option<String> {
+        self.show_bounding_box
+            .then(|| draw::BoundingBoxes::new(&self.styled_rounded_rectangle()).to_string())
+    }
+
+    fn check_invariants(&self) -> Result<(), String> {
+        draw::check_bounding_box(&self.styled_rounded_rectangle())
+    }
+
+    fn draw_underlay<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        if self.show_bounding_box {
+            draw::bounding_box(&self.styled_rounded_rectangle(), display)?;
+        }
+
+        Ok(())
+    }
+
+    fn draw<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        self.styled_rounded_rectangle().draw(display)
+    }
+
+    fn draw_reference<D>(&self, display: &mut D) -> Option<Result<(), Infallible>>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        Some(self.styled_rounded_rectangle().draw_reference(display))
+    }
+}
@@ -0,0 +1,113 @@
+use embedded_graphics::{
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::{PrimitiveStyle, PrimitiveStyleBuilder, Sector, Styled},
+};
+use framework::{draw, geometry::Outline, prelude::*};
+use std::convert::Infallible;
+
+pub struct SectorDebug {
+    center: Point,
+    diameter: u32,
+    angle_start: Angle,
+    angle_sweep: Angle,
+    stroke_width: u32,
+    show_bounding_box: bool,
+}
+
+impl SectorDebug {
+    fn styled_sector(&self) -> Styled<Sector, PrimitiveStyle<Rgb888>> {
+        let sector = Sector::with_center(
+            self.center,
+            self.diameter,
+            self.angle_start,
+            self.angle_sweep,
+        );
+
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(Rgb888::CSS_SPRING_GREEN)
+            .stroke_width(self.stroke_width)
+            .fill_color(Rgb888::CSS_DARK_SEA_GREEN)
+            .build();
+
+        sector.into_styled(style)
+    }
+}
+
+impl App for SectorDebug {
+    type Color = Rgb888;
+    const DISPLAY_SIZE: Size = Size::new(256, 256);
+
+    fn new() -> Self {
+        Self {
+            center: Point::new(128, 128),
+            diameter: 50,
+            angle_start: 0.0.deg(),
+            angle_sweep: 30.0.deg(),
+            stroke_width: 1,
+            show_bounding_box: false,
+        }
+    }
+
+    fn parameters(&mut self) -> Vec<Parameter> {
+        vec![
+            Parameter::new("center", &mut self.center),
+            Parameter::new("diameter", &mut self.diameter),
+            Parameter::new("start", &mut self.angle_start)
+                .range(0..=360)
+                .wrap(),
+            Parameter::new("sweep", &mut self.angle_sweep).range(-360..=360),
+            Parameter::new("stroke", &mut self.stroke_width),
+            Parameter::new("show BB", &mut self.show_bounding_box),
+        ]
+    }
+
+    fn ideal_geometry(&self) -> Vec<Outline> {
+        self.styled_sector().ideal_geometry()
+    }
+
+    fn status(&self) -> Option<String> {
+        self.show_bounding_box
+            .then(|| draw::BoundingBoxes::new(&self.styled_sector()).to_string())
+    }
+
+    fn check_invariants(&self) -> Result<(), String> {
+        draw::check_bounding_box(&self.styled_sector())
+    }
+
+    fn draw_underlay<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        if self.show_bounding_box {
+            draw::bounding_box(&self.styled_sector(), display)?;
+        }
+
+        Ok(())
+    }
+
+    fn draw<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        self.styled_sector().draw(display)
+    }
+
+    fn draw_overlay<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        if self.show_bounding_box {
+            draw::point(self.center, Rgb888::CSS_LIGHT_SKY_BLUE, display);
+        }
+
+        Ok(())
+    }
+
+    fn draw_reference<D>(&self, display: &mut D) -> Option<Result<(), Infallible>>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        Some(self.styled_sector().draw_reference(display))
+    }
+}
@@ -4,8 +4,8 @@ use embedded_graphics::{
     prelude::*,
     text::Text,
 };
-use embedded_graphics_simulator::{OutputSettingsBuilder, SimulatorDisplay, Window};
 use framework::prelude::*;
+use std::convert::Infallible;
 
 struct Face {
     name: &'static str,
@@ -14,105 +14,113 @@ struct Face {
     bold: Option<MonoFont<'static>>,
 }
 
-struct TextDecoration {}
+const FACES: [Face; 13] = [
+    Face {
+        name: "FONT_4X6",
+        default: FONT_4X6,
+        italic: None,
+        bold: None,
+    },
+    Face {
+        name: "FONT_5X7",
+        default: FONT_5X7,
+        italic: None,
+        bold: None,
+    },
+    Face {
+        name: "FONT_5X8",
+        default: FONT_5X8,
+        italic: None,
+        bold: None,
+    },
+    Face {
+        name: "FONT_6X9",
+        default: FONT_6X9,
+        italic: None,
+        bold: None,
+    },
+    Face {
+        name: "FONT_6X10",
+        default: FONT_6X10,
+        italic: None,
+        bold: None,
+    },
+    Face {
+        name: "FONT_6X12",
+        default: FONT_6X12,
+        italic: None,
+        bold: None,
+    },
+    Face {
+        name: "FONT_6X13",
+        default: FONT_6X13,
+        italic: Some(FONT_6X13_ITALIC),
+        bold: Some(FONT_6X13_BOLD),
+    },
+    Face {
+        name: "FONT_7X13",
+        default: FONT_7X13,
+        italic: Some(FONT_7X13_ITALIC),
+        bold: Some(FONT_7X13_BOLD),
+    },
+    Face {
+        name: "FONT_7X14",
+        default: FONT_7X14,
+        italic: Some(FONT_7X13_ITALIC),
+        bold: Some(FONT_7X14_BOLD),
+    },
+    Face {
+        name: "FONT_8X13",
+        default: FONT_8X13,
+        italic: Some(FONT_8X13_ITALIC),
+        bold: Some(FONT_8X13_BOLD),
+    },
+    Face {
+        name: "FONT_9X15",
+        default: FONT_9X15,
+        italic: None,
+        bold: Some(FONT_9X15_BOLD),
+    },
+    Face {
+        name: "FONT_9X18",
+        default: FONT_9X18,
+        italic: None,
+        bold: Some(FONT_9X18_BOLD),
+    },
+    Face {
+        name: "FONT_10X20",
+        default: FONT_10X20,
+        italic: None,
+        bold: None,
+    },
+];
+
+pub struct TextDecoration {
+    font: usize,
+}
 
 impl App for TextDecoration {
     type Color = Rgb888;
     const DISPLAY_SIZE: Size = Size::new(600, 450);
 
     fn new() -> Self {
-        Self {}
+        Self { font: 0 }
     }
 
     fn parameters(&mut self) -> Vec<Parameter> {
-        Vec::new()
-    }
+        // `MonoFont` doesn't implement `PartialEq`, so the font is selected by its index. The
+        // first option shows all fonts.
+        let names = std::iter::once("All")
+            .chain(FACES.iter().map(|face| face.name))
+            .collect::<Vec<_>>();
 
-    fn draw(
-        &self,
-        display: &mut SimulatorDisplay<Self::Color>,
-    ) -> Result<(), std::convert::Infallible> {
-        let fonts = vec![
-            Face {
-                name: "FONT_4X6",
-                default: FONT_4X6,
-                italic: None,
-                bold: None,
-            },
-            Face {
-                name: "FONT_5X7",
-                default: FONT_5X7,
-                italic: None,
-                bold: None,
-            },
-            Face {
-                name: "FONT_5X8",
-                default: FONT_5X8,
-                italic: None,
-                bold: None,
-            },
-            Face {
-                name: "FONT_6X9",
-                default: FONT_6X9,
-                italic: None,
-                bold: None,
-            },
-            Face {
-                name: "FONT_6X10",
-                default: FONT_6X10,
-                italic: None,
-                bold: None,
-            },
-            Face {
-                name: "FONT_6X12",
-                default: FONT_6X12,
-                italic: None,
-                bold: None,
-            },
-            Face {
-                name: "FONT_6X13",
-                default: FONT_6X13,
-                italic: Some(FONT_6X13_ITALIC),
-                bold: Some(FONT_6X13_BOLD),
-            },
-            Face {
-                name: "FONT_7X13",
-                default: FONT_7X13,
-                italic: Some(FONT_7X13_ITALIC),
-                bold: Some(FONT_7X13_BOLD),
-            },
-            Face {
-                name: "FONT_7X14",
-                default: FONT_7X14,
-                italic: Some(FONT_7X13_ITALIC),
-                bold: Some(FONT_7X14_BOLD),
-            },
-            Face {
-                name: "FONT_8X13",
-                default: FONT_8X13,
-                italic: Some(FONT_8X13_ITALIC),
-                bold: Some(FONT_8X13_BOLD),
-            },
-            Face {
-                name: "FONT_9X15",
-                default: FONT_9X15,
-                italic: None,
-                bold: Some(FONT_9X15_BOLD),
-            },
-            Face {
-                name: "FONT_9X18",
-                default: FONT_9X18,
-                italic: None,
-                bold: Some(FONT_9X18_BOLD),
-            },
-            Face {
-                name: "FONT_10X20",
-                default: FONT_10X20,
-                italic: None,
-                bold: None,
-            },
-        ];
+        vec![Parameter::choice_index("font", &mut self.font, &names)]
+    }
 
+    fn draw<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
         let text = "ABCabc[]\"qypilo";
 
         let mut position = Point::new(0, 10);
@@ -122,7 +130,11 @@ impl App for TextDecoration {
             default,
             bold,
             italic,
-        } in fonts.iter()
+        } in FACES
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.font == 0 || self.font == index + 1)
+            .map(|(_, face)| face)
         {
             let default_style = MonoTextStyleBuilder::new()
                 .font(default)
@@ -190,10 +202,3 @@ impl App for TextDecoration {
         Ok(())
     }
 }
-
-fn main() {
-    let settings = OutputSettingsBuilder::new().scale(2).build();
-    let window = Window::new("Builtin font decoration debugger", &settings);
-
-    TextDecoration::run(window);
-}
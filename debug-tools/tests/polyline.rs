@@ -0,0 +1,11 @@
+use debug_tools::polyline::PolylineDebug;
+use framework::{prelude::*, testing::assert_reference};
+
+#[test]
+fn polyline_joints() {
+    let mut app = PolylineDebug::new();
+    app.set_parameter("stroke=20").unwrap();
+    app.set_parameter("p3=190,60").unwrap();
+
+    assert_reference(&mut app, "tests/references/polyline_joints.png");
+}
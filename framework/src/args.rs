@@ -0,0 +1,136 @@
+use std::{env, path::PathBuf, str::FromStr, time::Duration};
+
+use crate::{grid::Axis, sweep::Sweep};
+
+/// Command line arguments accepted by every app.
+#[derive(Debug, Default)]
+pub(crate) struct Args {
+    /// Render a single frame to this PNG file instead of opening a window.
+    pub headless: Option<PathBuf>,
+    /// Include the menu in the headless output.
+    pub show_menu: bool,
+    /// Draw the menu in a side panel instead of on top of the app.
+    pub menu_panel: bool,
+    /// Output scale used in headless mode.
+    pub scale: Option<u32>,
+    /// Parameter file which is loaded on startup.
+    pub load: Option<PathBuf>,
+    /// Parameter assignments in the `name=value` format.
+    pub set: Vec<String>,
+    /// Parameter sweep which is rendered to an animated GIF in headless mode.
+    pub sweep: Option<Sweep>,
+    /// Parameter axes for which the invariants are checked instead of opening a window.
+    pub check: Vec<Axis>,
+    /// Maximum number of reported failures in check mode.
+    pub max_failures: Option<usize>,
+}
+
+impl Args {
+    /// Parses the arguments of the current process.
+    ///
+    /// Prints a message and exits the process if the arguments are invalid.
+    pub fn from_env() -> Self {
+        Self::parse_or_exit(env::args().skip(1))
+    }
+
+    /// Parses the given arguments.
+    ///
+    /// Prints a message and exits the process if the arguments are invalid.
+    pub fn parse_or_exit<I>(args: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+    {
+        match Self::parse(args) {
+            Ok(args) => args,
+            Err(message) => {
+                eprintln!("error: {}", message);
+                eprintln!();
+                eprintln!("{}", USAGE);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut ret = Self::default();
+
+        let mut step = None;
+        let mut frame_delay = None;
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--headless" => ret.headless = Some(next_value(&arg, &mut args)?),
+                "--menu" => ret.show_menu = true,
+                "--menu-panel" => ret.menu_panel = true,
+                "--scale" => ret.scale = Some(next_value(&arg, &mut args)?),
+                "--load" => ret.load = Some(next_value(&arg, &mut args)?),
+                "--set" => ret.set.push(next_value(&arg, &mut args)?),
+                "--sweep" => ret.sweep = Some(next_value(&arg, &mut args)?),
+                "--step" => step = Some(next_value(&arg, &mut args)?),
+                "--frame-delay" => frame_delay = Some(next_value(&arg, &mut args)?),
+                "--check" => ret.check.push(next_value(&arg, &mut args)?),
+                "--max-failures" => ret.max_failures = Some(next_value(&arg, &mut args)?),
+                "--help" | "-h" => {
+                    println!("{}", USAGE);
+                    std::process::exit(0);
+                }
+                _ => return Err(format!("unknown argument \"{}\"", arg)),
+            }
+        }
+
+        if ret.scale == Some(0) {
+            return Err("\"--scale\" must be at least 1".to_string());
+        }
+
+        if let Some(sweep) = &mut ret.sweep {
+            if ret.headless.is_none() {
+                return Err("\"--sweep\" requires \"--headless\"".to_string());
+            }
+
+            sweep.step = step.unwrap_or(sweep.step);
+            sweep.frame_delay = frame_delay.map_or(sweep.frame_delay, Duration::from_millis);
+        } else if step.is_some() || frame_delay.is_some() {
+            return Err("\"--step\" and \"--frame-delay\" require \"--sweep\"".to_string());
+        }
+
+        Ok(ret)
+    }
+}
+
+fn next_value<T, I>(arg: &str, args: &mut I) -> Result<T, String>
+where
+    T: FromStr,
+    I: Iterator<Item = String>,
+{
+    let value = args
+        .next()
+        .ok_or_else(|| format!("missing value for \"{}\"", arg))?;
+
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for \"{}\": \"{}\"", arg, value))
+}
+
+const USAGE: &str = "\
+Options:
+    --headless PATH   render a single frame to a PNG file without opening a window
+    --menu            include the menu in the headless output
+    --menu-panel      draw the menu in a side panel instead of on top of the app
+    --scale N         output scale in headless mode (default: 1)
+    --load PATH       load the parameter values from a file
+    --set NAME=VALUE  set a parameter value, e.g. --set center=40,60 or --set \"show BB=true\"
+    --sweep NAME=START..=END
+                      render one frame per parameter value to an animated GIF in headless
+                      mode, e.g. --headless circle.gif --sweep diameter=10..=60
+    --step N          step size of the sweep (default: 1)
+    --frame-delay MS  duration of a single frame of the sweep (default: 100)
+    --check NAME=START..=END, --check NAME=A|B, --check NAME
+                      check the invariants for all combinations of parameter values instead
+                      of opening a window, e.g. --check diameter=0..=64 --check stroke=0..=10,
+                      a bare NAME checks all valid values of a parameter with a range
+    --max-failures N  maximum number of reported failures in check mode (default: 10)
+    -h, --help        print this help";
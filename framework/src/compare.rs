@@ -0,0 +1,286 @@
+//! Pixel comparison of two drawings.
+//!
+//! A [`PixelDiff`] determines which pixels were drawn by only one of two drawings and which pixels
+//! were drawn by both drawings, but in different colors. This can be used to check that a rewrite
+//! of a rasterizer doesn't change the output:
+//!
+//! ```ignore
+//! let diff = PixelDiff::from_drawables(area, &old_circle, &new_circle);
+//! assert_eq!(diff.differing_points(), 0);
+//! ```
+
+use std::fmt;
+
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*, primitives::Rectangle};
+
+use crate::{draw::OverdrawDrawTarget, reference::ReferenceDrawable};
+
+/// Pixel difference between two drawings A and B.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PixelDiff {
+    bounding_box: Rectangle,
+    membership: Vec<Membership>,
+}
+
+/// Describes which drawings contain a pixel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Membership {
+    /// The pixel wasn't drawn.
+    Neither,
+    /// The pixel was only drawn by A.
+    OnlyA,
+    /// The pixel was only drawn by B.
+    OnlyB,
+    /// The pixel was drawn by A and B in the same color.
+    Both,
+    /// The pixel was drawn by A and B in different colors.
+    DifferentColor,
+}
+
+impl Membership {
+    /// Returns the color which is used to highlight pixels with this membership.
+    pub fn color(self) -> Option<Rgb888> {
+        match self {
+            Membership::Neither => None,
+            Membership::OnlyA => Some(Rgb888::CSS_RED),
+            Membership::OnlyB => Some(Rgb888::CSS_DODGER_BLUE),
+            Membership::Both => Some(Rgb888::CSS_GRAY),
+            Membership::DifferentColor => Some(Rgb888::CSS_YELLOW),
+        }
+    }
+}
+
+impl PixelDiff {
+    /// Creates a new pixel diff from the draw calls recorded by two overdraw targets.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the targets have different bounding boxes.
+    pub fn new<C: PixelColor>(a: &OverdrawDrawTarget<C>, b: &OverdrawDrawTarget<C>) -> Self {
+        let bounding_box = a.bounding_box();
+        assert_eq!(
+            bounding_box,
+            b.bounding_box(),
+            "the compared targets must have the same bounding box"
+        );
+
+        let membership = bounding_box
+            .points()
+            .map(|p| match (a.color(p), b.color(p)) {
+                (None, None) => Membership::Neither,
+                (Some(_), None) => Membership::OnlyA,
+                (None, Some(_)) => Membership::OnlyB,
+                (Some(a), Some(b)) if a == b => Membership::Both,
+                (Some(_), Some(_)) => Membership::DifferentColor,
+            })
+            .collect();
+
+        Self {
+            bounding_box,
+            membership,
+        }
+    }
+
+    /// Creates a new pixel diff by drawing two drawables.
+    ///
+    /// Pixels outside the bounding box are ignored.
+    pub fn from_drawables<A, B, C>(bounding_box: Rectangle, a: &A, b: &B) -> Self
+    where
+        A: Drawable<Color = C>,
+        B: Drawable<Color = C>,
+        C: PixelColor,
+    {
+        let mut target_a = OverdrawDrawTarget::new(bounding_box);
+        a.draw(&mut target_a).unwrap();
+
+        let mut target_b = OverdrawDrawTarget::new(bounding_box);
+        b.draw(&mut target_b).unwrap();
+
+        Self::new(&target_a, &target_b)
+    }
+
+    /// Creates a new pixel diff by comparing a drawable (A) against its reference (B).
+    ///
+    /// Pixels which the reference draws in the stroke color and e-g in the fill color, or vice
+    /// versa, are counted as [`Membership::DifferentColor`]. This makes errors in the boundary
+    /// between the stroke and the fill visible, even if both draw the same set of pixels.
+    ///
+    /// Pixels outside the bounding box are ignored.
+    pub fn from_reference<T, C>(bounding_box: Rectangle, drawable: &T) -> Self
+    where
+        T: Drawable<Color = C> + ReferenceDrawable<Color = C>,
+        C: PixelColor,
+    {
+        let mut target_a = OverdrawDrawTarget::new(bounding_box);
+        drawable.draw(&mut target_a).unwrap();
+
+        let mut target_b = OverdrawDrawTarget::new(bounding_box);
+        drawable.draw_reference(&mut target_b).unwrap();
+
+        Self::new(&target_a, &target_b)
+    }
+
+    /// Returns the membership of a point.
+    ///
+    /// Points outside the bounding box are treated as not drawn.
+    pub fn membership(&self, point: Point) -> Membership {
+        if !self.bounding_box.contains(point) {
+            return Membership::Neither;
+        }
+
+        let delta = point - self.bounding_box.top_left;
+        let index = delta.x as usize + delta.y as usize * self.bounding_box.size.width as usize;
+
+        self.membership[index]
+    }
+
+    /// Returns the number of points with the given membership.
+    pub fn count(&self, membership: Membership) -> usize {
+        self.membership.iter().filter(|m| **m == membership).count()
+    }
+
+    /// Returns the number of points which were only drawn by A or only drawn by B or which were
+    /// drawn in different colors.
+    pub fn differing_points(&self) -> usize {
+        self.count(Membership::OnlyA)
+            + self.count(Membership::OnlyB)
+            + self.count(Membership::DifferentColor)
+    }
+
+    /// Draws the diff.
+    ///
+    /// Points which were only drawn by A are drawn in red, points which were only drawn by B are
+    /// drawn in blue, points which were drawn by both in different colors are drawn in yellow and
+    /// points which were drawn by both in the same color are drawn in gray. Points which weren't
+    /// drawn are left unchanged.
+    pub fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget,
+        D::Color: From<Rgb888>,
+    {
+        target.draw_iter(self.bounding_box.points().filter_map(|p| {
+            self.membership(p)
+                .color()
+                .map(|color| Pixel(p, color.into()))
+        }))
+    }
+}
+
+impl fmt::Display for PixelDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "only A: {}px, only B: {}px, color: {}px, both: {}px",
+            self.count(Membership::OnlyA),
+            self.count(Membership::OnlyB),
+            self.count(Membership::DifferentColor),
+            self.count(Membership::Both)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::primitives::{
+        Circle, PrimitiveStyle, PrimitiveStyleBuilder, StrokeAlignment,
+    };
+
+    #[test]
+    fn identical_drawables() {
+        let circle =
+            Circle::new(Point::new(2, 2), 10).into_styled(PrimitiveStyle::with_fill(Rgb888::GREEN));
+        let area = Rectangle::new(Point::zero(), Size::new(15, 15));
+
+        let diff = PixelDiff::from_drawables(area, &circle, &circle);
+
+        assert_eq!(diff.differing_points(), 0);
+        assert_eq!(diff.count(Membership::Both), 80);
+    }
+
+    #[test]
+    fn different_coverage() {
+        let a =
+            Circle::new(Point::new(2, 2), 10).into_styled(PrimitiveStyle::with_fill(Rgb888::GREEN));
+        let b = a.translate(Point::new(1, 0));
+        let area = Rectangle::new(Point::zero(), Size::new(15, 15));
+
+        let diff = PixelDiff::from_drawables(area, &a, &b);
+
+        assert_eq!(diff.membership(Point::new(2, 7)), Membership::OnlyA);
+        assert_eq!(diff.membership(Point::new(12, 7)), Membership::OnlyB);
+        assert_eq!(diff.count(Membership::DifferentColor), 0);
+        assert_eq!(
+            diff.differing_points(),
+            diff.count(Membership::OnlyA) + diff.count(Membership::OnlyB)
+        );
+    }
+
+    #[test]
+    fn different_colors() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(Rgb888::RED)
+            .stroke_width(1)
+            .fill_color(Rgb888::GREEN)
+            .build();
+        let a = Circle::new(Point::new(2, 2), 10).into_styled(style);
+        let b =
+            Circle::new(Point::new(2, 2), 10).into_styled(PrimitiveStyle::with_fill(Rgb888::GREEN));
+        let area = Rectangle::new(Point::zero(), Size::new(15, 15));
+
+        let diff = PixelDiff::from_drawables(area, &a, &b);
+
+        assert_eq!(diff.count(Membership::OnlyA), 0);
+        assert_eq!(diff.count(Membership::OnlyB), 0);
+        assert!(diff.count(Membership::DifferentColor) > 0);
+        assert_eq!(
+            diff.membership(Point::new(2, 7)),
+            Membership::DifferentColor
+        );
+        assert_eq!(diff.membership(Point::new(7, 7)), Membership::Both);
+        assert_eq!(diff.membership(Point::new(0, 0)), Membership::Neither);
+    }
+
+    #[test]
+    fn stroke_fill_boundary() {
+        let style = |stroke_width| {
+            PrimitiveStyleBuilder::new()
+                .stroke_color(Rgb888::RED)
+                .stroke_width(stroke_width)
+                .stroke_alignment(StrokeAlignment::Inside)
+                .fill_color(Rgb888::GREEN)
+                .build()
+        };
+        let circle = Circle::new(Point::new(2, 2), 20);
+        let area = Rectangle::new(Point::zero(), Size::new(25, 25));
+
+        // An inside stroke doesn't change the drawn area, only the boundary between the stroke and
+        // the fill.
+        let mut a = OverdrawDrawTarget::new(area);
+        circle.into_styled(style(3)).draw(&mut a).unwrap();
+        let mut b = OverdrawDrawTarget::new(area);
+        circle.into_styled(style(4)).draw_reference(&mut b).unwrap();
+
+        let diff = PixelDiff::new(&a, &b);
+
+        assert_eq!(diff.count(Membership::OnlyA), 0);
+        assert_eq!(diff.count(Membership::OnlyB), 0);
+        assert!(diff.count(Membership::DifferentColor) > 0);
+    }
+
+    #[test]
+    fn reference_circle() {
+        let style = PrimitiveStyleBuilder::new()
+            .stroke_color(Rgb888::RED)
+            .stroke_width(3)
+            .fill_color(Rgb888::GREEN)
+            .build();
+        let circle = Circle::new(Point::new(4, 4), 20).into_styled(style);
+        let area = Rectangle::new(Point::zero(), Size::new(30, 30));
+
+        let diff = PixelDiff::from_reference(area, &circle);
+
+        assert!(diff.count(Membership::Both) > 0);
+        assert_eq!(diff.differing_points(), 0, "{}", diff);
+    }
+}
@@ -1,10 +1,10 @@
-use std::{convert::Infallible, marker::PhantomData, ops::Range};
+use std::{convert::Infallible, fmt, marker::PhantomData};
 
 use embedded_graphics::{
+    pixelcolor::Rgb888,
     prelude::*,
     primitives::{Circle, PrimitiveStyle, Rectangle},
 };
-use embedded_graphics_simulator::SimulatorDisplay;
 
 /// Draws a cross around a point.
 pub fn point<D>(p: Point, color: D::Color, target: &mut D)
@@ -23,84 +23,706 @@ where
 /// `Drawable` impl is drawn in gray. The second bounding box is determined by calculating the
 /// minimum and maximum coordinates of all drawn pixels. For non transparent strokes both bounding
 /// boxes should have the same size and only the gray bounding box should be visible.
-pub fn bounding_box<T, C>(drawable: &T, display: &mut SimulatorDisplay<C>)
+///
+/// The bounding boxes should be drawn before the drawable, e.g. in
+/// [`App::draw_underlay`](crate::App::draw_underlay), to not hide the edge pixels of the drawable.
+///
+/// Returns both bounding boxes, which can be used to display the mismatch.
+pub fn bounding_box<T, D>(drawable: &T, display: &mut D) -> Result<BoundingBoxes, D::Error>
+where
+    T: Drawable<Color = D::Color> + Dimensions,
+    D: DrawTarget,
+    D::Color: WebColors,
+{
+    let bounding_boxes = BoundingBoxes::new(drawable);
+
+    bounding_boxes
+        .actual
+        .into_styled(PrimitiveStyle::with_stroke(D::Color::CSS_TOMATO, 1))
+        .draw(display)?;
+
+    bounding_boxes
+        .expected
+        .into_styled(PrimitiveStyle::with_stroke(D::Color::CSS_DIM_GRAY, 1))
+        .draw(display)?;
+
+    Ok(bounding_boxes)
+}
+
+/// Checks that the bounding box of a drawable matches the drawn pixels.
+///
+/// This performs the same check as [`bounding_box`] without drawing anything and returns an error
+/// message if the bounding boxes don't match. Use [`BoundingBoxes`] to get the detailed result.
+pub fn check_bounding_box<T>(drawable: &T) -> Result<(), String>
 where
     T: Drawable + Dimensions,
-    C: PixelColor + WebColors,
 {
-    // Determine actual bounding box
-    let mut bb_target = BoundingBoxDrawTarget::new();
-    drawable.draw(&mut bb_target).unwrap();
-
-    bb_target
-        .bounding_box
-        .into_styled(PrimitiveStyle::with_stroke(C::CSS_TOMATO, 1))
-        .draw(display)
-        .unwrap();
+    let bounding_boxes = BoundingBoxes::new(drawable);
 
-    drawable
-        .bounding_box()
-        .into_styled(PrimitiveStyle::with_stroke(C::CSS_DIM_GRAY, 1))
-        .draw(display)
-        .unwrap();
+    match bounding_boxes.mismatch() {
+        Some(mismatch) => Err(format!(
+            "bounding box mismatch: {} (expected {:?}, drawn pixels cover {:?})",
+            mismatch, bounding_boxes.expected, bounding_boxes.actual
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Expected and actual bounding box of a drawable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBoxes {
+    /// Bounding box returned by the `Dimensions` impl.
+    pub expected: Rectangle,
+    /// Bounding box of all drawn pixels.
+    pub actual: Rectangle,
+}
+
+impl BoundingBoxes {
+    /// Determines the bounding boxes of a drawable.
+    pub fn new<T>(drawable: &T) -> Self
+    where
+        T: Drawable + Dimensions,
+    {
+        let mut bb_target = BoundingBoxDrawTarget::<T::Color>::new();
+        drawable.draw(&mut bb_target).unwrap();
+
+        Self {
+            expected: drawable.bounding_box(),
+            actual: bb_target.drawn_area(),
+        }
+    }
+
+    /// Returns the mismatch between the bounding boxes or `None` if they match.
+    ///
+    /// Drawables without any drawn pixels are accepted if their bounding box is empty.
+    pub fn mismatch(&self) -> Option<BoundingBoxMismatch> {
+        if self.expected == self.actual
+            || (self.expected.is_zero_sized() && self.actual.is_zero_sized())
+        {
+            return None;
+        }
+
+        let expected_columns = self.expected.columns();
+        let expected_rows = self.expected.rows();
+        let actual_columns = self.actual.columns();
+        let actual_rows = self.actual.rows();
+
+        Some(BoundingBoxMismatch {
+            left: expected_columns.start - actual_columns.start,
+            top: expected_rows.start - actual_rows.start,
+            right: actual_columns.end - expected_columns.end,
+            bottom: actual_rows.end - expected_rows.end,
+        })
+    }
 }
 
+impl fmt::Display for BoundingBoxes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.mismatch() {
+            Some(mismatch) => write!(f, "BB mismatch: {}", mismatch),
+            None => write!(f, "BB ok"),
+        }
+    }
+}
+
+/// Mismatch between the expected and actual bounding box.
+///
+/// Each field contains the distance by which the drawn pixels extend past the expected bounding
+/// box at this edge. Negative values mean that the expected bounding box is too large.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBoxMismatch {
+    /// Difference at the left edge.
+    pub left: i32,
+    /// Difference at the top edge.
+    pub top: i32,
+    /// Difference at the right edge.
+    pub right: i32,
+    /// Difference at the bottom edge.
+    pub bottom: i32,
+}
+
+impl BoundingBoxMismatch {
+    /// Returns the names and differences of all edges which don't match.
+    pub fn differing_edges(&self) -> impl Iterator<Item = (&'static str, i32)> {
+        [
+            ("left", self.left),
+            ("top", self.top),
+            ("right", self.right),
+            ("bottom", self.bottom),
+        ]
+        .into_iter()
+        .filter(|(_, difference)| *difference != 0)
+    }
+}
+
+impl fmt::Display for BoundingBoxMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, (edge, difference)) in self.differing_edges().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{} {:+}", edge, difference)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Draw target that determines the bounding box of all drawn pixels.
+///
+/// The target accepts pixels at arbitrary coordinates. Areas passed to `fill_contiguous` and
+/// `fill_solid` are added as a whole, without iterating over the individual pixels.
 #[derive(Debug)]
-struct BoundingBoxDrawTarget<C> {
-    bounding_box: Rectangle,
+pub struct BoundingBoxDrawTarget<C> {
+    /// Top left and bottom right corner of the drawn area.
+    corners: Option<(Point, Point)>,
     color_type: PhantomData<C>,
 }
 
 impl<C> BoundingBoxDrawTarget<C> {
-    fn new() -> Self {
+    /// Creates a new bounding box draw target.
+    pub fn new() -> Self {
         Self {
-            bounding_box: Rectangle::zero(),
+            corners: None,
             color_type: PhantomData,
         }
     }
+
+    /// Returns the bounding box of all drawn pixels.
+    ///
+    /// A zero sized rectangle is returned if no pixels were drawn. The size saturates at
+    /// `u32::MAX` if the drawn pixels span the whole `i32` range.
+    pub fn drawn_area(&self) -> Rectangle {
+        match self.corners {
+            Some((top_left, bottom_right)) => {
+                let width = i64::from(bottom_right.x) - i64::from(top_left.x) + 1;
+                let height = i64::from(bottom_right.y) - i64::from(top_left.y) + 1;
+
+                Rectangle::new(
+                    top_left,
+                    Size::new(
+                        u32::try_from(width).unwrap_or(u32::MAX),
+                        u32::try_from(height).unwrap_or(u32::MAX),
+                    ),
+                )
+            }
+            None => Rectangle::zero(),
+        }
+    }
+
+    fn add_corners(&mut self, top_left: Point, bottom_right: Point) {
+        self.corners = Some(match self.corners {
+            Some((min, max)) => (
+                Point::new(min.x.min(top_left.x), min.y.min(top_left.y)),
+                Point::new(max.x.max(bottom_right.x), max.y.max(bottom_right.y)),
+            ),
+            None => (top_left, bottom_right),
+        });
+    }
+
+    fn add_area(&mut self, area: &Rectangle) {
+        if let Some(bottom_right) = area.bottom_right() {
+            self.add_corners(area.top_left, bottom_right);
+        }
+    }
+}
+
+impl<C> Default for BoundingBoxDrawTarget<C> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<C: PixelColor> DrawTarget for BoundingBoxDrawTarget<C> {
     type Color = C;
-    type Error = ();
+    type Error = Infallible;
 
     fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
     where
         I: IntoIterator<Item = Pixel<Self::Color>>,
     {
-        let mut columns = self.bounding_box.columns();
-        let mut rows = self.bounding_box.rows();
-
         for Pixel(p, _c) in pixels {
-            extend_range(&mut columns, p.x);
-            extend_range(&mut rows, p.y);
+            self.add_corners(p, p);
         }
 
-        self.bounding_box = Rectangle::new(
-            Point::new(columns.start, rows.start),
-            Size::new(
-                (columns.end - columns.start) as u32,
-                (rows.end - rows.start) as u32,
-            ),
-        );
+        Ok(())
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, _colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.add_area(area);
+
+        Ok(())
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, _color: Self::Color) -> Result<(), Self::Error> {
+        self.add_area(area);
+
+        Ok(())
+    }
+}
+
+impl<C> Dimensions for BoundingBoxDrawTarget<C> {
+    /// Returns a very large area to make sure that drawables aren't clipped.
+    ///
+    /// The area is limited to `±2^30`, because e-g calculates the corners of rectangles in `i32`
+    /// coordinates, which would overflow for larger areas. Pixels outside of this area are still
+    /// included in the drawn area.
+    fn bounding_box(&self) -> Rectangle {
+        Rectangle::new(
+            Point::new_equal(i32::MIN / 2),
+            Size::new_equal(i32::MAX as u32),
+        )
+    }
+}
+
+/// Draw target that records statistics about the calls to the `DrawTarget` methods.
+///
+/// All calls are forwarded to the wrapped target. This can be used to check if a drawable uses
+/// the fast `fill_contiguous` and `fill_solid` methods instead of drawing individual pixels with
+/// `draw_iter`.
+#[derive(Debug)]
+pub struct StatisticsDrawTarget<'a, D> {
+    target: &'a mut D,
+    statistics: DrawStatistics,
+    watched_point: Option<Point>,
+}
+
+impl<'a, D> StatisticsDrawTarget<'a, D> {
+    /// Creates a new statistics draw target.
+    pub fn new(target: &'a mut D) -> Self {
+        Self {
+            target,
+            statistics: DrawStatistics::default(),
+            watched_point: None,
+        }
+    }
+
+    /// Records which method was used to draw the given point.
+    ///
+    /// The result is available in [`DrawStatistics::watched_point_method`].
+    pub fn watch(&mut self, point: Point) {
+        self.watched_point = Some(point);
+    }
+
+    /// Returns the statistics.
+    pub fn statistics(&self) -> &DrawStatistics {
+        &self.statistics
+    }
+
+    /// Returns the statistics and releases the wrapped target.
+    pub fn into_statistics(self) -> DrawStatistics {
+        self.statistics
+    }
+}
+
+impl<D> StatisticsDrawTarget<'_, D> {
+    fn watch_area(&mut self, area: &Rectangle, method: DrawMethod) {
+        if self.watched_point.is_some_and(|p| area.contains(p)) {
+            self.statistics.watched_point_method = Some(method);
+        }
+    }
+}
+
+impl<D: DrawTarget> DrawTarget for StatisticsDrawTarget<'_, D> {
+    type Color = D::Color;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let statistics = &mut self.statistics;
+        let watched_point = self.watched_point;
+
+        statistics.draw_iter.calls += 1;
+
+        self.target
+            .draw_iter(pixels.into_iter().inspect(|Pixel(p, _)| {
+                statistics.draw_iter.pixels += 1;
+
+                if Some(*p) == watched_point {
+                    statistics.watched_point_method = Some(DrawMethod::DrawIter);
+                }
+            }))
+    }
+
+    fn fill_contiguous<I>(&mut self, area: &Rectangle, colors: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Self::Color>,
+    {
+        self.statistics.fill_contiguous.add(area);
+        self.statistics.fill_contiguous_areas.push(*area);
+        self.watch_area(area, DrawMethod::FillContiguous);
+
+        self.target.fill_contiguous(area, colors)
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        self.statistics.fill_solid.add(area);
+        self.statistics.fill_solid_areas.push(*area);
+        self.watch_area(area, DrawMethod::FillSolid);
+
+        self.target.fill_solid(area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let area = self.target.bounding_box();
+        self.statistics.clear.add(&area);
+        self.watch_area(&area, DrawMethod::Clear);
+
+        self.target.clear(color)
+    }
+}
+
+impl<D: Dimensions> Dimensions for StatisticsDrawTarget<'_, D> {
+    fn bounding_box(&self) -> Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+/// Statistics about the calls to the `DrawTarget` methods.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DrawStatistics {
+    /// Calls to `draw_iter`.
+    pub draw_iter: MethodStatistics,
+    /// Calls to `fill_contiguous`.
+    pub fill_contiguous: MethodStatistics,
+    /// Calls to `fill_solid`.
+    pub fill_solid: MethodStatistics,
+    /// Calls to `clear`.
+    pub clear: MethodStatistics,
+    /// Areas passed to `fill_contiguous`.
+    pub fill_contiguous_areas: Vec<Rectangle>,
+    /// Areas passed to `fill_solid`.
+    pub fill_solid_areas: Vec<Rectangle>,
+    /// The method that was last used to draw the watched point.
+    ///
+    /// See [`StatisticsDrawTarget::watch`].
+    pub watched_point_method: Option<DrawMethod>,
+}
+
+/// `DrawTarget` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DrawMethod {
+    /// `draw_iter`
+    DrawIter,
+    /// `fill_contiguous`
+    FillContiguous,
+    /// `fill_solid`
+    FillSolid,
+    /// `clear`
+    Clear,
+}
+
+impl DrawMethod {
+    /// Returns the method name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::DrawIter => "draw_iter",
+            Self::FillContiguous => "fill_contiguous",
+            Self::FillSolid => "fill_solid",
+            Self::Clear => "clear",
+        }
+    }
+}
+
+/// Statistics for a single `DrawTarget` method.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MethodStatistics {
+    /// Number of calls.
+    pub calls: usize,
+    /// Number of pixels delivered to the method.
+    ///
+    /// For `fill_contiguous`, `fill_solid` and `clear` this is the number of pixels in the area.
+    pub pixels: usize,
+}
+
+impl fmt::Display for DrawStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let methods = [
+            (DrawMethod::DrawIter, self.draw_iter),
+            (DrawMethod::FillContiguous, self.fill_contiguous),
+            (DrawMethod::FillSolid, self.fill_solid),
+            (DrawMethod::Clear, self.clear),
+        ];
+
+        for (index, (method, statistics)) in methods.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            write!(
+                f,
+                "{:<15} {:>4}x {:>6}px",
+                method.name(),
+                statistics.calls,
+                statistics.pixels
+            )?;
+        }
 
         Ok(())
     }
 }
 
-fn extend_range(range: &mut Range<i32>, value: i32) {
-    // MSRV: use `Range::is_empty`
-    if range.start >= range.end {
-        range.start = value;
-        range.end = value + 1;
-    } else {
-        range.start = range.start.min(value);
-        range.end = range.end.max(value + 1);
+impl MethodStatistics {
+    fn add(&mut self, area: &Rectangle) {
+        self.calls += 1;
+        self.pixels += area.size.width as usize * area.size.height as usize;
+    }
+}
+
+/// Draw target that counts how many times each pixel was drawn.
+///
+/// The color which was last used to draw a pixel is also recorded. Pixels outside the bounding
+/// box are ignored.
+#[derive(Debug)]
+pub struct OverdrawDrawTarget<C> {
+    bounding_box: Rectangle,
+    counts: Vec<u32>,
+    colors: Vec<Option<C>>,
+}
+
+impl<C: PixelColor> OverdrawDrawTarget<C> {
+    /// Creates a new overdraw draw target.
+    pub fn new(bounding_box: Rectangle) -> Self {
+        let len = bounding_box.size.width as usize * bounding_box.size.height as usize;
+
+        Self {
+            bounding_box,
+            counts: vec![0; len],
+            colors: vec![None; len],
+        }
+    }
+
+    /// Returns how many times a point was drawn.
+    pub fn count(&self, point: Point) -> u32 {
+        self.index(point).map_or(0, |index| self.counts[index])
+    }
+
+    /// Returns the color which was last used to draw a point.
+    ///
+    /// Returns `None` if the point wasn't drawn.
+    pub fn color(&self, point: Point) -> Option<C> {
+        self.index(point).and_then(|index| self.colors[index])
+    }
+
+    /// Returns the maximum number of times a single point was drawn.
+    pub fn max_count(&self) -> u32 {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Returns the number of points that were drawn more than once.
+    pub fn overdrawn_points(&self) -> usize {
+        self.counts.iter().filter(|count| **count > 1).count()
+    }
+
+    /// Draws a heatmap of the counts.
+    ///
+    /// Points which were drawn once are drawn in gray and points which were drawn multiple times
+    /// are drawn in yellow, orange or red. Points which weren't drawn are left unchanged.
+    pub fn draw_heatmap<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget,
+        D::Color: From<Rgb888>,
+    {
+        target.draw_iter(self.bounding_box.points().filter_map(|p| {
+            let color = match self.count(p) {
+                0 => return None,
+                1 => Rgb888::CSS_GRAY,
+                2 => Rgb888::CSS_YELLOW,
+                3 => Rgb888::CSS_ORANGE,
+                _ => Rgb888::CSS_RED,
+            };
+
+            Some(Pixel(p, color.into()))
+        }))
+    }
+
+    fn index(&self, point: Point) -> Option<usize> {
+        self.bounding_box.contains(point).then(|| {
+            let delta = point - self.bounding_box.top_left;
+
+            delta.x as usize + delta.y as usize * self.bounding_box.size.width as usize
+        })
     }
 }
 
-impl<C> OriginDimensions for BoundingBoxDrawTarget<C> {
-    fn size(&self) -> Size {
-        Size::new_equal(256)
+impl<C: PixelColor> DrawTarget for OverdrawDrawTarget<C> {
+    type Color = C;
+    type Error = Infallible;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(p, c) in pixels {
+            if let Some(index) = self.index(p) {
+                self.counts[index] += 1;
+                self.colors[index] = Some(c);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<C> Dimensions for OverdrawDrawTarget<C> {
+    fn bounding_box(&self) -> Rectangle {
+        self.bounding_box
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    use super::*;
+
+    /// Drawable which draws a fixed area, but reports a different bounding box.
+    struct Misreported {
+        drawn: Rectangle,
+        reported: Rectangle,
+    }
+
+    impl Drawable for Misreported {
+        type Color = BinaryColor;
+        type Output = ();
+
+        fn draw<D>(&self, target: &mut D) -> Result<(), D::Error>
+        where
+            D: DrawTarget<Color = Self::Color>,
+        {
+            target.fill_solid(&self.drawn, BinaryColor::On)
+        }
+    }
+
+    impl Dimensions for Misreported {
+        fn bounding_box(&self) -> Rectangle {
+            self.reported
+        }
+    }
+
+    #[test]
+    fn matching_bounding_box() {
+        let circle = Circle::new(Point::new(-3, 4), 9)
+            .into_styled(PrimitiveStyle::with_stroke(BinaryColor::On, 3));
+
+        let bounding_boxes = BoundingBoxes::new(&circle);
+
+        assert_eq!(bounding_boxes.expected, bounding_boxes.actual);
+        assert_eq!(bounding_boxes.mismatch(), None);
+        assert_eq!(bounding_boxes.to_string(), "BB ok");
+        assert_eq!(check_bounding_box(&circle), Ok(()));
+    }
+
+    #[test]
+    fn mismatching_bounding_box() {
+        let drawable = Misreported {
+            drawn: Rectangle::new(Point::new(8, 10), Size::new(10, 5)),
+            reported: Rectangle::new(Point::new(10, 10), Size::new(10, 10)),
+        };
+
+        let bounding_boxes = BoundingBoxes::new(&drawable);
+        assert_eq!(bounding_boxes.actual, drawable.drawn);
+        assert_eq!(bounding_boxes.expected, drawable.reported);
+
+        let mismatch = bounding_boxes.mismatch().unwrap();
+        assert_eq!(
+            mismatch,
+            BoundingBoxMismatch {
+                left: 2,
+                top: 0,
+                right: -2,
+                bottom: -5,
+            }
+        );
+        assert_eq!(
+            mismatch.differing_edges().collect::<Vec<_>>(),
+            [("left", 2), ("right", -2), ("bottom", -5)]
+        );
+        assert_eq!(
+            bounding_boxes.to_string(),
+            "BB mismatch: left +2, right -2, bottom -5"
+        );
+        assert!(check_bounding_box(&drawable).is_err());
+    }
+
+    #[test]
+    fn empty_drawable() {
+        let drawable = Misreported {
+            drawn: Rectangle::new(Point::new(8, 10), Size::zero()),
+            reported: Rectangle::new(Point::new(20, 20), Size::zero()),
+        };
+
+        assert_eq!(BoundingBoxes::new(&drawable).mismatch(), None);
+    }
+
+    #[test]
+    fn empty_draw() {
+        let mut target = BoundingBoxDrawTarget::<BinaryColor>::new();
+        assert_eq!(target.drawn_area(), Rectangle::zero());
+
+        target.draw_iter([]).unwrap();
+        target
+            .fill_solid(
+                &Rectangle::new(Point::new(5, 5), Size::zero()),
+                BinaryColor::On,
+            )
+            .unwrap();
+        assert_eq!(target.drawn_area(), Rectangle::zero());
+    }
+
+    #[test]
+    fn negative_coordinates() {
+        let mut target = BoundingBoxDrawTarget::new();
+        target
+            .draw_iter([
+                Pixel(Point::new(-10, 3), BinaryColor::On),
+                Pixel(Point::new(-2, -7), BinaryColor::Off),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            target.drawn_area(),
+            Rectangle::with_corners(Point::new(-10, -7), Point::new(-2, 3))
+        );
+    }
+
+    #[test]
+    fn fill_solid_and_fill_contiguous() {
+        let mut target = BoundingBoxDrawTarget::new();
+
+        let solid = Rectangle::new(Point::new(-5, 10), Size::new(3, 4));
+        target.fill_solid(&solid, BinaryColor::On).unwrap();
+        assert_eq!(target.drawn_area(), solid);
+
+        let contiguous = Rectangle::new(Point::new(20, -3), Size::new(2, 2));
+        target
+            .fill_contiguous(&contiguous, std::iter::empty())
+            .unwrap();
+        assert_eq!(
+            target.drawn_area(),
+            Rectangle::with_corners(Point::new(-5, -3), Point::new(21, 13))
+        );
+    }
+
+    #[test]
+    fn saturated_size() {
+        let mut target = BoundingBoxDrawTarget::new();
+        target
+            .draw_iter([
+                Pixel(Point::new_equal(i32::MIN), BinaryColor::On),
+                Pixel(Point::new(i32::MAX, 0), BinaryColor::On),
+            ])
+            .unwrap();
+
+        assert_eq!(
+            target.drawn_area(),
+            Rectangle::new(Point::new_equal(i32::MIN), Size::new(u32::MAX, 1 << 31 | 1))
+        );
     }
 }
@@ -0,0 +1,398 @@
+//! Ideal geometry.
+//!
+//! The types in this module describe the mathematically ideal outlines of primitives, which can
+//! be drawn on top of the rasterized primitives to judge whether the correct pixels were drawn.
+//!
+//! All coordinates use a continuous coordinate system in which the pixel at `(x, y)` covers the
+//! area from `(x, y)` to `(x + 1, y + 1)`. The center of this pixel is at `(x + 0.5, y + 0.5)`.
+
+use std::f32::consts::TAU;
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{
+        Arc, Circle, Ellipse, Line, PrimitiveStyle, Rectangle, Sector, StrokeAlignment, Styled,
+    },
+};
+
+/// Outline of a shape.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Outline {
+    /// Shape of the outline.
+    pub shape: Shape,
+    /// Outline kind.
+    pub kind: OutlineKind,
+}
+
+impl Outline {
+    /// Creates a new outline.
+    pub fn new(shape: Shape, kind: OutlineKind) -> Self {
+        Self { shape, kind }
+    }
+}
+
+/// Outline kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutlineKind {
+    /// Outline of the unstyled primitive.
+    Shape,
+    /// Inner or outer edge of the stroke.
+    StrokeEdge,
+}
+
+/// Shape in continuous coordinates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shape {
+    /// Elliptical arc.
+    ///
+    /// The angles use the same convention as in e-g: `0°` points to the right and positive
+    /// angles are clockwise. Full ellipses use a sweep angle of `360°`.
+    Arc {
+        /// Center point.
+        center: (f32, f32),
+        /// Horizontal and vertical radius.
+        radii: (f32, f32),
+        /// Start angle.
+        start: Angle,
+        /// Sweep angle.
+        sweep: Angle,
+    },
+    /// Line segment.
+    Line {
+        /// Start point.
+        start: (f32, f32),
+        /// End point.
+        end: (f32, f32),
+    },
+}
+
+impl Shape {
+    /// Creates a full ellipse.
+    pub fn ellipse(center: (f32, f32), radii: (f32, f32)) -> Self {
+        Self::Arc {
+            center,
+            radii,
+            start: Angle::zero(),
+            sweep: Angle::from_degrees(360.0),
+        }
+    }
+
+    /// Returns the point on an arc at the given angle.
+    fn arc_point(center: (f32, f32), radii: (f32, f32), angle: f32) -> (f32, f32) {
+        (
+            center.0 + radii.0 * angle.cos(),
+            center.1 + radii.1 * angle.sin(),
+        )
+    }
+
+    /// Converts the shape into a list of points.
+    ///
+    /// Curves are approximated by line segments which are at most `max_segment_length` long.
+    pub fn to_polyline(&self, max_segment_length: f32) -> Vec<(f32, f32)> {
+        match *self {
+            Shape::Arc {
+                center,
+                radii,
+                start,
+                sweep,
+            } => {
+                let start = start.to_radians();
+                let sweep = sweep.to_radians();
+
+                let length = sweep.abs() * radii.0.max(radii.1);
+                let segments = ((length / max_segment_length).ceil() as usize).clamp(8, 4096);
+
+                (0..=segments)
+                    .map(|i| {
+                        let angle = start + sweep * i as f32 / segments as f32;
+                        Self::arc_point(center, radii, angle)
+                    })
+                    .collect()
+            }
+            Shape::Line { start, end } => vec![start, end],
+        }
+    }
+}
+
+/// Ideal geometry of a primitive.
+pub trait IdealGeometry {
+    /// Returns the ideal outlines.
+    ///
+    /// The returned outlines include the outline of the primitive and the inner and outer edges
+    /// of the stroke.
+    fn ideal_geometry(&self) -> Vec<Outline>;
+}
+
+/// Returns the stroke widths outside and inside of the shape.
+///
+/// This uses the same rules as e-g to distribute odd stroke widths.
+pub fn stroke_widths<C: PixelColor>(style: &PrimitiveStyle<C>) -> (f32, f32) {
+    if style.stroke_color.is_none() {
+        return (0.0, 0.0);
+    }
+
+    let width = style.stroke_width;
+    let (outside, inside) = match style.stroke_alignment {
+        StrokeAlignment::Inside => (0, width),
+        StrokeAlignment::Center => (width / 2, width.saturating_add(1) / 2),
+        StrokeAlignment::Outside => (width, 0),
+    };
+
+    (outside as f32, inside as f32)
+}
+
+/// Returns the center and radii of the ellipse that fits inside a rectangle.
+pub(crate) fn ellipse_in(rectangle: &Rectangle) -> ((f32, f32), (f32, f32)) {
+    let radii = (
+        rectangle.size.width as f32 / 2.0,
+        rectangle.size.height as f32 / 2.0,
+    );
+    let center = (
+        rectangle.top_left.x as f32 + radii.0,
+        rectangle.top_left.y as f32 + radii.1,
+    );
+
+    (center, radii)
+}
+
+/// Returns the outline of an elliptical arc and the edges of its stroke.
+fn arc_outlines<C: PixelColor>(
+    bounding_box: &Rectangle,
+    start: Angle,
+    sweep: Angle,
+    style: &PrimitiveStyle<C>,
+) -> Vec<Outline> {
+    let (center, radii) = ellipse_in(bounding_box);
+    let (outside, inside) = stroke_widths(style);
+
+    let mut outlines = vec![Outline::new(
+        Shape::Arc {
+            center,
+            radii,
+            start,
+            sweep,
+        },
+        OutlineKind::Shape,
+    )];
+
+    if outside + inside > 0.0 {
+        for offset in [outside, -inside] {
+            let radii = ((radii.0 + offset).max(0.0), (radii.1 + offset).max(0.0));
+
+            outlines.push(Outline::new(
+                Shape::Arc {
+                    center,
+                    radii,
+                    start,
+                    sweep,
+                },
+                OutlineKind::StrokeEdge,
+            ));
+        }
+    }
+
+    outlines
+}
+
+impl<C: PixelColor> IdealGeometry for Styled<Circle, PrimitiveStyle<C>> {
+    fn ideal_geometry(&self) -> Vec<Outline> {
+        arc_outlines(
+            &self.primitive.bounding_box(),
+            Angle::zero(),
+            Angle::from_degrees(360.0),
+            &self.style,
+        )
+    }
+}
+
+impl<C: PixelColor> IdealGeometry for Styled<Ellipse, PrimitiveStyle<C>> {
+    fn ideal_geometry(&self) -> Vec<Outline> {
+        arc_outlines(
+            &self.primitive.bounding_box(),
+            Angle::zero(),
+            Angle::from_degrees(360.0),
+            &self.style,
+        )
+    }
+}
+
+impl<C: PixelColor> IdealGeometry for Styled<Arc, PrimitiveStyle<C>> {
+    fn ideal_geometry(&self) -> Vec<Outline> {
+        arc_outlines(
+            &self.primitive.to_circle().bounding_box(),
+            self.primitive.angle_start,
+            self.primitive.angle_sweep,
+            &self.style,
+        )
+    }
+}
+
+/// Returns the edges of a sector which is offset by `offset`.
+///
+/// Positive offsets grow the sector and negative offsets shrink it. The offset radial edges meet
+/// in a miter join. Returns an empty list if nothing is left of the offset sector.
+fn sector_offset_edges(
+    center: (f32, f32),
+    radius: f32,
+    start: f32,
+    sweep: f32,
+    offset: f32,
+) -> Vec<Outline> {
+    let outer_radius = radius + offset;
+    if outer_radius <= offset.abs() {
+        return Vec::new();
+    }
+
+    let sign = sweep.signum();
+    let end = start + sweep;
+
+    // Unit vectors along the radial edges and outward facing normals.
+    let directions = [(start.cos(), start.sin()), (end.cos(), end.sin())];
+    let normals = [
+        (start.sin() * sign, -start.cos() * sign),
+        (-end.sin() * sign, end.cos() * sign),
+    ];
+
+    // Intersection of the offset radial edges, relative to the center.
+    let det = normals[0].0 * normals[1].1 - normals[0].1 * normals[1].0;
+    let corner = if det.abs() > 1e-6 {
+        (
+            offset * (normals[1].1 - normals[0].1) / det,
+            offset * (normals[0].0 - normals[1].0) / det,
+        )
+    } else {
+        (normals[0].0 * offset, normals[0].1 * offset)
+    };
+
+    // Distance along the edge directions at which the offset edges meet the offset arc.
+    let edge_length = (outer_radius * outer_radius - offset * offset).sqrt();
+
+    let mut outlines = Vec::new();
+    for (direction, normal) in directions.iter().zip(normals.iter()) {
+        let corner_distance = corner.0 * direction.0 + corner.1 * direction.1;
+        if corner_distance >= edge_length {
+            continue;
+        }
+
+        outlines.push(Outline::new(
+            Shape::Line {
+                start: (center.0 + corner.0, center.1 + corner.1),
+                end: (
+                    center.0 + normal.0 * offset + direction.0 * edge_length,
+                    center.1 + normal.1 * offset + direction.1 * edge_length,
+                ),
+            },
+            OutlineKind::StrokeEdge,
+        ));
+    }
+
+    // The offset arc ends at the offset radial edges.
+    let extension = offset.atan2(edge_length);
+    let arc_sweep = sweep + 2.0 * extension * sign;
+    if arc_sweep * sign > 0.0 {
+        outlines.push(Outline::new(
+            Shape::Arc {
+                center,
+                radii: (outer_radius, outer_radius),
+                start: Angle::from_radians(start - extension * sign),
+                sweep: Angle::from_radians(arc_sweep),
+            },
+            OutlineKind::StrokeEdge,
+        ));
+    }
+
+    outlines
+}
+
+impl<C: PixelColor> IdealGeometry for Styled<Sector, PrimitiveStyle<C>> {
+    fn ideal_geometry(&self) -> Vec<Outline> {
+        let sector = &self.primitive;
+        let bounding_box = sector.to_circle().bounding_box();
+
+        let start = sector.angle_start.to_radians();
+        let sweep = sector.angle_sweep.to_radians();
+
+        // Sectors with a sweep angle of at least 360° are drawn as circles.
+        if sweep.abs() >= TAU {
+            return arc_outlines(
+                &bounding_box,
+                Angle::zero(),
+                Angle::from_degrees(360.0),
+                &self.style,
+            );
+        }
+
+        let (center, radii) = ellipse_in(&bounding_box);
+
+        let mut outlines = vec![Outline::new(
+            Shape::Arc {
+                center,
+                radii,
+                start: sector.angle_start,
+                sweep: sector.angle_sweep,
+            },
+            OutlineKind::Shape,
+        )];
+
+        for angle in [start, start + sweep] {
+            outlines.push(Outline::new(
+                Shape::Line {
+                    start: center,
+                    end: Shape::arc_point(center, radii, angle),
+                },
+                OutlineKind::Shape,
+            ));
+        }
+
+        if sweep == 0.0 {
+            return outlines;
+        }
+
+        let (outside, inside) = stroke_widths(&self.style);
+        if outside + inside > 0.0 {
+            for offset in [outside, -inside] {
+                outlines.extend(sector_offset_edges(center, radii.0, start, sweep, offset));
+            }
+        }
+
+        outlines
+    }
+}
+
+impl<C: PixelColor> IdealGeometry for Styled<Line, PrimitiveStyle<C>> {
+    fn ideal_geometry(&self) -> Vec<Outline> {
+        let line = &self.primitive;
+
+        let start = (line.start.x as f32 + 0.5, line.start.y as f32 + 0.5);
+        let end = (line.end.x as f32 + 0.5, line.end.y as f32 + 0.5);
+
+        let mut outlines = vec![Outline::new(Shape::Line { start, end }, OutlineKind::Shape)];
+
+        let width = if self.style.stroke_color.is_some() {
+            self.style.stroke_width as f32
+        } else {
+            0.0
+        };
+
+        let delta = (end.0 - start.0, end.1 - start.1);
+        let length = (delta.0 * delta.0 + delta.1 * delta.1).sqrt();
+
+        if width > 1.0 && length > 0.0 {
+            let normal = (-delta.1 / length, delta.0 / length);
+
+            for offset in [width / 2.0, -width / 2.0] {
+                let offset = (normal.0 * offset, normal.1 * offset);
+
+                outlines.push(Outline::new(
+                    Shape::Line {
+                        start: (start.0 + offset.0, start.1 + offset.1),
+                        end: (end.0 + offset.0, end.1 + offset.1),
+                    },
+                    OutlineKind::StrokeEdge,
+                ));
+            }
+        }
+
+        outlines
+    }
+}
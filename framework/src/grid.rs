@@ -0,0 +1,346 @@
+//! Exhaustive invariant checks.
+//!
+//! A grid check evaluates [`App::check_invariants`] for every combination of parameter values
+//! and reports the parameter sets for which an invariant was violated. This makes it possible to
+//! check thousands of cases unattended, e.g. all combinations of diameters and stroke widths in
+//! the circle debugger:
+//!
+//! ```text
+//! cargo run -- circle --check diameter=0..=64 --check stroke=0..=10
+//! ```
+//!
+//! If only the parameter name is given, the axis contains all valid values of the parameter, as
+//! returned by [`Parameter::valid_values`](crate::Parameter::valid_values).
+
+use std::{fmt, str::FromStr};
+
+use crate::{sweep::Sweep, App, AppExt};
+
+/// Parameter axis of a grid check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Axis {
+    /// Parameter name.
+    pub parameter: String,
+    /// Parameter values.
+    pub values: Vec<String>,
+}
+
+impl Axis {
+    /// Creates an axis from an inclusive range of integer values.
+    pub fn range(parameter: &str, start: i32, end: i32) -> Self {
+        Self::from(Sweep::new(parameter, start, end))
+    }
+
+    /// Creates an axis from a list of values.
+    ///
+    /// The values are parsed according to the type of the parameter, e.g. `"true"` and `"false"`
+    /// for a boolean parameter.
+    pub fn values(parameter: &str, values: &[&str]) -> Self {
+        Self {
+            parameter: parameter.to_string(),
+            values: values.iter().map(|value| value.to_string()).collect(),
+        }
+    }
+}
+
+impl From<Sweep> for Axis {
+    fn from(sweep: Sweep) -> Self {
+        Self {
+            values: sweep.values().map(|value| value.to_string()).collect(),
+            parameter: sweep.parameter,
+        }
+    }
+}
+
+impl FromStr for Axis {
+    type Err = String;
+
+    /// Parses an axis in the `name=start..=end`, `name=value1|value2|...` or `name` format.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (parameter, values) = match s.split_once('=') {
+            Some(assignment) => assignment,
+            None => {
+                return Ok(Self {
+                    parameter: s.trim().to_string(),
+                    values: Vec::new(),
+                })
+            }
+        };
+
+        if values.contains("..") {
+            return s.parse::<Sweep>().map(Self::from);
+        }
+
+        Ok(Self {
+            parameter: parameter.trim().to_string(),
+            values: values.split('|').map(|v| v.trim().to_string()).collect(),
+        })
+    }
+}
+
+/// Parameter set for which an invariant was violated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    /// Parameter assignments in the `name=value` format.
+    pub parameters: Vec<String>,
+    /// Error message returned by [`App::check_invariants`].
+    pub message: String,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.parameters.join(" "), self.message)
+    }
+}
+
+/// Result of a grid check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridReport {
+    /// Number of checked parameter sets.
+    pub checked: usize,
+    /// Number of parameter sets for which an invariant was violated.
+    pub failed: usize,
+    /// The first failing parameter sets.
+    pub failures: Vec<Failure>,
+}
+
+impl GridReport {
+    /// Returns `true` if no invariant was violated.
+    pub fn is_ok(&self) -> bool {
+        self.failed == 0
+    }
+}
+
+impl fmt::Display for GridReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "checked {} parameter sets, {} failed",
+            self.checked, self.failed
+        )?;
+
+        for failure in &self.failures {
+            write!(f, "\n    {}", failure)?;
+        }
+
+        if self.failures.len() < self.failed {
+            write!(f, "\n    ...")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks the invariants of an app for every combination of parameter values.
+///
+/// At most `max_failures` failing parameter sets are included in the report. Parameters which
+/// aren't part of an axis keep their current value. Axes without values use all valid values of
+/// the parameter. Returns an error if a value couldn't be assigned to a parameter or if the valid
+/// values of a parameter aren't known.
+pub fn check_grid<T: App>(
+    app: &mut T,
+    axes: &[Axis],
+    max_failures: usize,
+) -> Result<GridReport, String> {
+    let axes = axes
+        .iter()
+        .map(|axis| resolve_axis(app, axis))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut report = GridReport {
+        checked: 0,
+        failed: 0,
+        failures: Vec::new(),
+    };
+
+    if axes.iter().any(|axis| axis.values.is_empty()) {
+        return Ok(report);
+    }
+
+    let mut indices = vec![0; axes.len()];
+
+    loop {
+        let parameters = axes
+            .iter()
+            .zip(indices.iter())
+            .map(|(axis, index)| format!("{}={}", axis.parameter, axis.values[*index]))
+            .collect::<Vec<_>>();
+
+        for assignment in &parameters {
+            app.set_parameter(assignment)?;
+        }
+
+        report.checked += 1;
+
+        if let Err(message) = app.check_invariants() {
+            report.failed += 1;
+
+            if report.failures.len() < max_failures {
+                report.failures.push(Failure {
+                    parameters,
+                    message,
+                });
+            }
+        }
+
+        // Advance to the next combination, with the last axis changing fastest.
+        let mut axis = axes.len();
+        loop {
+            if axis == 0 {
+                return Ok(report);
+            }
+            axis -= 1;
+
+            indices[axis] += 1;
+            if indices[axis] < axes[axis].values.len() {
+                break;
+            }
+            indices[axis] = 0;
+        }
+    }
+}
+
+/// Fills in the valid values of the parameter if the axis has no values.
+fn resolve_axis<T: App>(app: &mut T, axis: &Axis) -> Result<Axis, String> {
+    if !axis.values.is_empty() {
+        return Ok(axis.clone());
+    }
+
+    let parameters = app.parameters();
+    let parameter = parameters
+        .iter()
+        .find(|parameter| parameter.name() == axis.parameter)
+        .ok_or_else(|| format!("unknown parameter \"{}\"", axis.parameter))?;
+
+    let values = parameter.valid_values().ok_or_else(|| {
+        format!(
+            "parameter \"{}\" has no range or too many values, use NAME=START..=END instead",
+            axis.parameter
+        )
+    })?;
+
+    Ok(Axis {
+        parameter: axis.parameter.clone(),
+        values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, convert::Infallible};
+
+    use embedded_graphics::{pixelcolor::Rgb888, prelude::*};
+
+    use super::*;
+    use crate::Parameter;
+
+    /// App which records the checked parameter sets and fails for odd sizes.
+    struct TestApp {
+        size: u32,
+        fill: bool,
+        checked: RefCell<Vec<(u32, bool)>>,
+    }
+
+    impl App for TestApp {
+        type Color = Rgb888;
+        const DISPLAY_SIZE: Size = Size::new(16, 16);
+
+        fn new() -> Self {
+            Self {
+                size: 0,
+                fill: false,
+                checked: RefCell::new(Vec::new()),
+            }
+        }
+
+        fn parameters(&mut self) -> Vec<Parameter> {
+            vec![
+                Parameter::new("size", &mut self.size).range(0..=3),
+                Parameter::new("fill", &mut self.fill),
+            ]
+        }
+
+        fn check_invariants(&self) -> Result<(), String> {
+            self.checked.borrow_mut().push((self.size, self.fill));
+
+            if self.size % 2 == 1 {
+                Err(format!("odd size {}", self.size))
+            } else {
+                Ok(())
+            }
+        }
+
+        fn draw<D>(&self, _display: &mut D) -> Result<(), Infallible>
+        where
+            D: DrawTarget<Color = Self::Color, Error = Infallible>,
+        {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn parse_axes() {
+        assert_eq!("size=1..=3".parse(), Ok(Axis::range("size", 1, 3)));
+        assert_eq!(
+            "fill = true | false".parse(),
+            Ok(Axis::values("fill", &["true", "false"]))
+        );
+        assert_eq!("size".parse(), Ok(Axis::values("size", &[])));
+    }
+
+    #[test]
+    fn combination_order() {
+        let mut app = TestApp::new();
+        let axes = [
+            Axis::range("size", 0, 2),
+            "fill=false|true".parse().unwrap(),
+        ];
+
+        let report = check_grid(&mut app, &axes, 10).unwrap();
+
+        assert_eq!(
+            app.checked.into_inner(),
+            [
+                (0, false),
+                (0, true),
+                (1, false),
+                (1, true),
+                (2, false),
+                (2, true)
+            ]
+        );
+        assert_eq!(report.checked, 6);
+        assert_eq!(report.failed, 2);
+        assert_eq!(
+            report.failures[0],
+            Failure {
+                parameters: vec!["size=1".to_string(), "fill=false".to_string()],
+                message: "odd size 1".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn max_failures() {
+        let mut app = TestApp::new();
+        let axes = ["size".parse().unwrap(), "fill".parse().unwrap()];
+
+        let report = check_grid(&mut app, &axes, 3).unwrap();
+
+        assert_eq!(report.checked, 8);
+        assert_eq!(report.failed, 4);
+        assert_eq!(report.failures.len(), 3);
+        assert!(report.to_string().ends_with("\n    ..."));
+
+        let report = check_grid(&mut app, &axes, 0).unwrap();
+        assert_eq!(report.failed, 4);
+        assert!(report.failures.is_empty());
+    }
+
+    #[test]
+    fn invalid_axes() {
+        let mut app = TestApp::new();
+
+        assert!(check_grid(&mut app, &["radius".parse().unwrap()], 10).is_err());
+        assert!(check_grid(&mut app, &[Axis::values("fill", &["maybe"])], 10).is_err());
+    }
+}
@@ -0,0 +1,90 @@
+use std::fmt::Debug;
+
+use embedded_graphics::prelude::*;
+use embedded_graphics_simulator::{sdl2::Keycode, SimulatorDisplay, SimulatorEvent};
+
+use crate::draw::DrawMethod;
+
+/// Pixel inspector.
+///
+/// The inspector shows the position and color of the pixel under the mouse cursor in the status
+/// panel.
+pub(crate) struct Inspector<C> {
+    enabled: bool,
+    position: Option<Point>,
+    color: Option<C>,
+    method: Option<DrawMethod>,
+}
+
+impl<C: PixelColor + Debug> Inspector<C> {
+    pub(crate) fn new() -> Self {
+        Self {
+            enabled: false,
+            position: None,
+            color: None,
+            method: None,
+        }
+    }
+
+    /// Handles an event.
+    ///
+    /// `I` toggles the inspector. Mouse events are only observed and should also be passed on to
+    /// the menu.
+    pub(crate) fn handle_event(&mut self, event: &SimulatorEvent) -> bool {
+        match event {
+            SimulatorEvent::KeyDown {
+                keycode: Keycode::I,
+                ..
+            } => {
+                self.enabled ^= true;
+                return true;
+            }
+            SimulatorEvent::MouseMove { point }
+            | SimulatorEvent::MouseButtonDown { point, .. }
+            | SimulatorEvent::MouseButtonUp { point, .. } => self.position = Some(*point),
+            _ => {}
+        }
+
+        false
+    }
+
+    /// Returns the position of the inspected pixel or `None` if the inspector is disabled.
+    pub(crate) fn position(&self) -> Option<Point> {
+        self.position.filter(|_| self.enabled)
+    }
+
+    /// Reads the color of the inspected pixel.
+    ///
+    /// The `scene` must contain the app without any overlays and `scene_offset` is the position
+    /// of the top left corner of the scene in logical coordinates. `method` is the draw method
+    /// which was used to draw the pixel, if known.
+    pub(crate) fn update(
+        &mut self,
+        scene: &SimulatorDisplay<C>,
+        scene_offset: Point,
+        method: Option<DrawMethod>,
+    ) {
+        self.method = method;
+        self.color = self
+            .position
+            .map(|p| p - scene_offset)
+            .filter(|p| self.enabled && scene.bounding_box().contains(*p))
+            .map(|p| scene.get_pixel(p));
+    }
+
+    /// Returns the status text or `None` if the inspector is disabled.
+    pub(crate) fn status(&self) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+
+        Some(match (self.position, self.color) {
+            (Some(position), Some(color)) => {
+                let method = self.method.map_or("-", DrawMethod::name);
+
+                format!("({}, {}) {}\n{:?}", position.x, position.y, method, color)
+            }
+            _ => "outside of display".to_string(),
+        })
+    }
+}
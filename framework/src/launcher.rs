@@ -0,0 +1,127 @@
+use std::env;
+
+use embedded_graphics_simulator::{OutputSettings, Window};
+
+use crate::{args::Args, picker::Picker, run_app, App};
+
+/// Launcher for multiple apps.
+///
+/// The launcher opens the app whose name was passed as the first command line argument. All
+/// other arguments are handled in the same way as in [`AppExt::run`](crate::AppExt::run).
+/// Pressing `Tab` opens a picker to switch to another app without restarting the process.
+///
+/// ```ignore
+/// let settings = OutputSettingsBuilder::new().scale(3).build();
+///
+/// Launcher::new()
+///     .add::<ArcDebug>("arc", "Arc debugger", &settings)
+///     .add::<CircleDebug>("circle", "Circle debugger", &settings)
+///     .run();
+/// ```
+pub struct Launcher {
+    apps: Vec<LauncherEntry>,
+}
+
+struct LauncherEntry {
+    name: &'static str,
+    title: &'static str,
+    output_settings: OutputSettings,
+    run: fn(Window, &Args, &str, Option<&mut Picker>) -> Option<usize>,
+}
+
+impl Launcher {
+    /// Creates a new launcher without any apps.
+    pub fn new() -> Self {
+        Self { apps: Vec::new() }
+    }
+
+    /// Adds an app.
+    ///
+    /// The `name` is used to select the app on the command line and in the picker and the
+    /// `title` is used as the window title.
+    pub fn add<T: App>(
+        mut self,
+        name: &'static str,
+        title: &'static str,
+        output_settings: &OutputSettings,
+    ) -> Self {
+        self.apps.push(LauncherEntry {
+            name,
+            title,
+            output_settings: output_settings.clone(),
+            run: run_app::<T>,
+        });
+
+        self
+    }
+
+    /// Runs the launcher.
+    ///
+    /// If no app name was passed on the command line the first app is opened and the picker is
+    /// shown.
+    pub fn run(self) {
+        assert!(!self.apps.is_empty(), "no apps were added to the launcher");
+
+        let mut args = env::args().skip(1).peekable();
+        let name = args.next_if(|arg| !arg.starts_with('-'));
+
+        if name.is_none() && args.peek().map(String::as_str) == Some("--list") {
+            self.print_apps();
+            return;
+        }
+
+        let mut index = match &name {
+            Some(name) => match self.apps.iter().position(|app| app.name == name) {
+                Some(index) => index,
+                None => {
+                    eprintln!("error: unknown app \"{}\"", name);
+                    eprintln!();
+                    self.print_apps();
+                    std::process::exit(1);
+                }
+            },
+            None => 0,
+        };
+
+        let mut args = Args::parse_or_exit(args);
+        if name.is_none() && (args.headless.is_some() || !args.check.is_empty()) {
+            eprintln!("error: an app name is required in headless and check mode");
+            std::process::exit(1);
+        }
+
+        let mut picker = Picker::new(self.apps.iter().map(|app| app.name).collect());
+        picker.set_open(name.is_none());
+
+        loop {
+            let app = &self.apps[index];
+            picker.set_current(index);
+
+            let window = Window::new(app.title, &app.output_settings);
+
+            match (app.run)(window, &args, app.name, Some(&mut picker)) {
+                Some(selected) => index = selected,
+                None => break,
+            }
+
+            // Only the initially selected app uses the command line arguments, except for the
+            // menu layout.
+            args = Args {
+                menu_panel: args.menu_panel,
+                ..Args::default()
+            };
+        }
+    }
+
+    fn print_apps(&self) {
+        println!("Available apps:");
+        for app in &self.apps {
+            println!("    {:<24}{}", app.name, app.title);
+        }
+    }
+}
+
+impl Default for Launcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,25 +1,60 @@
 use embedded_graphics::{
-    pixelcolor::{BinaryColor, Rgb888},
+    pixelcolor::{BinaryColor, Rgb888, WebColors},
     prelude::*,
+    primitives::Rectangle,
+};
+use embedded_graphics_simulator::{
+    sdl2::{Keycode, Mod},
+    OutputSettings, OutputSettingsBuilder, SimulatorDisplay, SimulatorEvent, Window,
+};
+use std::{
+    convert::Infallible,
+    env,
+    fmt::Debug,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
 };
-use embedded_graphics_simulator::{SimulatorDisplay, Window};
-use std::time::{Duration, Instant};
 
+mod args;
+pub mod compare;
 pub mod draw;
+pub mod geometry;
+pub mod grid;
+mod inspector;
+mod launcher;
 mod menu;
 mod parameter;
+mod parameter_file;
+mod picker;
+pub mod reference;
+mod status;
+pub mod sweep;
+pub mod testing;
+mod viewport;
 
+use args::Args;
+use compare::PixelDiff;
+use draw::{DrawStatistics, OverdrawDrawTarget, StatisticsDrawTarget};
+use geometry::Outline;
+use inspector::Inspector;
+pub use launcher::Launcher;
 use menu::Menu;
-pub use parameter::Parameter;
+pub use parameter::{Constraints, Parameter, MAX_VALID_VALUES, STROKE_ALIGNMENTS};
+use picker::{Picker, PickerEvent};
+use sweep::Sweep;
+use viewport::Viewport;
 
 pub mod prelude {
-    pub use crate::{parameter::Parameter, App, AppExt};
+    pub use crate::{
+        geometry::IdealGeometry, parameter::Parameter, reference::ReferenceDrawable, App, AppExt,
+        Launcher,
+    };
 }
 
 const MIN_FRAME_DURATION: Duration = Duration::from_millis(1000 / 60);
 
 pub trait App {
-    type Color: PixelColor + From<BinaryColor> + Into<Rgb888> + From<Rgb888>;
+    type Color: PixelColor + From<BinaryColor> + Into<Rgb888> + From<Rgb888> + Debug;
     const DISPLAY_SIZE: Size;
 
     fn new() -> Self;
@@ -34,46 +69,606 @@ pub trait App {
 
     fn parameters(&mut self) -> Vec<Parameter>;
 
-    fn draw(
-        &self,
-        display: &mut SimulatorDisplay<Self::Color>,
-    ) -> Result<(), std::convert::Infallible>;
+    /// Returns the ideal outlines of the drawn primitives.
+    ///
+    /// The outlines are drawn on top of the app if the ideal geometry overlay is enabled. See
+    /// [`IdealGeometry`](geometry::IdealGeometry) for an easy way to get the outlines of e-g
+    /// primitives.
+    fn ideal_geometry(&self) -> Vec<Outline> {
+        Vec::new()
+    }
+
+    /// Returns additional information about the app which is shown in the status panel.
+    fn status(&self) -> Option<String> {
+        None
+    }
+
+    /// Checks invariants which should hold for all parameter values.
+    ///
+    /// Returns a description of the first violated invariant. The invariants are evaluated for
+    /// every combination of parameter values if the `--check` argument is used, see
+    /// [`grid::check_grid`].
+    fn check_invariants(&self) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Draws the app.
+    ///
+    /// The target might be translated or clipped to the visible area if the viewport is zoomed
+    /// or panned.
+    fn draw<D>(&self, display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>;
+
+    /// Draws debug underlays, like bounding boxes, below the app.
+    ///
+    /// Underlays are drawn before [`App::draw`], which makes sure that they don't hide any pixels
+    /// drawn by the app. Like overlays they aren't included in the compare mode, the overdraw
+    /// heatmap and the draw call statistics.
+    fn draw_underlay<D>(&self, _display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        Ok(())
+    }
+
+    /// Draws debug overlays, like marked points, on top of the app.
+    ///
+    /// Overlays aren't included in the compare mode, the overdraw heatmap and the draw call
+    /// statistics.
+    fn draw_overlay<D>(&self, _display: &mut D) -> Result<(), Infallible>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        Ok(())
+    }
+
+    /// Draws the reference which the app is compared against in the compare mode.
+    ///
+    /// This can be used to compare the output of e-g against a reference implementation, like
+    /// the [`reference`] rasterizer. Returns `None`, without drawing anything, if the app has no
+    /// reference, which is the default.
+    fn draw_reference<D>(&self, _display: &mut D) -> Option<Result<(), Infallible>>
+    where
+        D: DrawTarget<Color = Self::Color, Error = Infallible>,
+    {
+        None
+    }
 }
 
 pub trait AppExt: App {
+    /// Runs the app.
+    ///
+    /// If the `--headless PATH` argument was passed to the process a single frame is written to
+    /// `PATH` instead of opening the window. Combined with `--sweep NAME=START..=END` one frame
+    /// is rendered for each value of the parameter and the frames are written to an animated
+    /// GIF. The initial parameter values can be loaded from a parameter file by using the
+    /// `--load PATH` argument and individual parameters can be overridden by `--set NAME=VALUE`
+    /// arguments.
+    ///
+    /// While the app is running the current parameter values can be saved by pressing `Ctrl+S`
+    /// and restored by pressing `Ctrl+L`. The file passed to `--load` is used for both key
+    /// bindings, or `<executable name>.parameters` if no file was specified.
+    ///
+    /// Pressing `I` toggles the pixel inspector, which shows the position and color of the pixel
+    /// under the mouse cursor and the `DrawTarget` method that was used to draw it.
+    ///
+    /// The view can be zoomed in and out by pressing `+` and `-` and panned by dragging with the
+    /// right mouse button. Pressing `0` resets the view. If the view is zoomed in `G` cycles
+    /// through the grid overlays, which show the pixel boundaries and pixel centers.
+    ///
+    /// Pressing `C` shows statistics about the `DrawTarget` method calls made by the app and
+    /// outlines the areas passed to `fill_contiguous` (orange) and `fill_solid` (blue). `H`
+    /// replaces the app by a heatmap, which shows how many times each pixel was drawn.
+    ///
+    /// Pressing `O` shows the ideal outlines returned by [`App::ideal_geometry`]. The outline of
+    /// the shape is drawn in magenta and the stroke edges are drawn in cyan.
+    ///
+    /// Pressing `D` toggles the compare mode, which compares the app (A) against the output of
+    /// [`App::draw_reference`] (B). Apps without a reference are compared against a snapshot of
+    /// the parameter values at the time the compare mode was enabled instead. Pixels which are
+    /// only drawn by A are shown in red, pixels only drawn by B in blue, pixels drawn by both in
+    /// different colors in yellow and pixels drawn by both in the same color in gray.
+    ///
+    /// If one or more `--check NAME=START..=END` or `--check NAME=A|B` arguments are used no
+    /// window is opened and [`App::check_invariants`] is evaluated for all combinations of the
+    /// parameter values instead.
+    ///
+    /// The menu is drawn on top of the app by default. If the `--menu-panel` argument is used the
+    /// menu is drawn in a side panel next to the app instead. Pressing `M` hides the menu. The
+    /// selected parameter can still be changed with the arrow keys and by dragging while the menu
+    /// is hidden.
+    ///
+    /// Numeric values are changed in steps of 10 while shift is pressed and in steps of 100 while
+    /// control is pressed. Holding down a key accelerates the change.
+    ///
+    /// The value of the selected parameter can also be entered directly by typing a number or
+    /// pressing `E`, e.g. `120,-4` for a point. Press `Enter` to confirm the value and `Escape`
+    /// to cancel. Values which start with `0` or `-` have to be entered after pressing `E`,
+    /// because these keys reset and zoom the view.
+    ///
+    /// Menu items can be selected by clicking them and checkboxes are toggled by clicking on
+    /// them. Numbers can be changed with the mouse wheel or by dragging them horizontally.
+    ///
+    /// Use a [`Launcher`] to run one of multiple apps in the same executable.
     fn run(window: Window);
+
+    /// Sets a parameter value by using a `name=value` assignment.
+    ///
+    /// The name must match the name passed to [`Parameter::new`] and the value is parsed
+    /// according to the type of the parameter, e.g. `center=40,60` or `show BB=true`.
+    fn set_parameter(&mut self, assignment: &str) -> Result<(), String>;
+
+    /// Renders a single frame.
+    fn render(&mut self, show_menu: bool) -> SimulatorDisplay<Self::Color>;
+
+    /// Renders a single frame to a PNG file.
+    ///
+    /// This doesn't require a window and can therefore also be used in tests or on machines
+    /// without a display.
+    fn render_to_png<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        output_settings: &OutputSettings,
+        show_menu: bool,
+    ) -> image::ImageResult<()>;
+
+    /// Renders a parameter sweep to an animated GIF file.
+    ///
+    /// The parameter is set to each value of the sweep before the frame is rendered. The
+    /// rendered frames are scaled up by the integer factor `scale`.
+    fn render_sweep_to_gif<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        sweep: &Sweep,
+        scale: u32,
+        show_menu: bool,
+    ) -> Result<(), String>;
 }
 
 impl<T: App> AppExt for T {
-    fn run(mut window: Window) {
-        let mut app = T::new();
-        let mut display = SimulatorDisplay::new(T::DISPLAY_SIZE);
+    fn run(window: Window) {
+        let args = Args::from_env();
+
+        run_app::<T>(window, &args, &executable_name(), None);
+    }
+
+    fn set_parameter(&mut self, assignment: &str) -> Result<(), String> {
+        parameter_file::set(&mut self.parameters(), assignment)
+    }
 
+    fn render(&mut self, show_menu: bool) -> SimulatorDisplay<Self::Color> {
+        let mut display = SimulatorDisplay::new(T::DISPLAY_SIZE);
         let mut menu = Menu::new();
+
+        draw_frame(self, show_menu.then_some(&mut menu), &mut display);
+
+        display
+    }
+
+    fn render_to_png<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        output_settings: &OutputSettings,
+        show_menu: bool,
+    ) -> image::ImageResult<()> {
+        self.render(show_menu)
+            .to_rgb_output_image(output_settings)
+            .save_png(path)
+    }
+
+    fn render_sweep_to_gif<P: AsRef<Path>>(
+        &mut self,
+        path: P,
+        sweep: &Sweep,
+        scale: u32,
+        show_menu: bool,
+    ) -> Result<(), String> {
+        sweep::render_to_gif(self, path.as_ref(), sweep, scale, show_menu)
+    }
+}
+
+/// Runs an app until the window is closed or another app is selected in the picker.
+///
+/// The `name` is used to determine the default parameter file. Returns the index of the app that
+/// was selected in the picker or `None` if the window was closed.
+pub(crate) fn run_app<T: App>(
+    mut window: Window,
+    args: &Args,
+    name: &str,
+    mut picker: Option<&mut Picker>,
+) -> Option<usize> {
+    let mut app = T::new();
+
+    if let Some(path) = &args.load {
+        if let Err(e) = parameter_file::load(&mut app.parameters(), path) {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    for assignment in &args.set {
+        if let Err(e) = app.set_parameter(assignment) {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+
+    if !args.check.is_empty() {
+        let max_failures = args.max_failures.unwrap_or(10);
+
+        match grid::check_grid(&mut app, &args.check, max_failures) {
+            Ok(report) => {
+                println!("{}", report);
+
+                if !report.is_ok() {
+                    std::process::exit(1);
+                }
+            }
+            Err(e) => {
+                eprintln!("error: {}", e);
+                std::process::exit(1);
+            }
+        }
+
+        return None;
+    }
+
+    if let Some(path) = &args.headless {
+        let scale = args.scale.unwrap_or(1);
+
+        let result = match &args.sweep {
+            Some(sweep) => app.render_sweep_to_gif(path, sweep, scale, args.show_menu),
+            None => {
+                let output_settings = OutputSettingsBuilder::new().scale(scale).build();
+
+                app.render_to_png(path, &output_settings, args.show_menu)
+                    .map_err(|e| format!("couldn't write \"{}\": {}", path.display(), e))
+            }
+        };
+
+        if let Err(e) = result {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+
+        return None;
+    }
+
+    let parameter_file = args
+        .load
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(format!("{}.parameters", name)));
+
+    let menu_panel = args.menu_panel.then(|| {
+        let width = Menu::panel_width(&app.parameters());
+
+        Rectangle::new(
+            Point::new(T::DISPLAY_SIZE.width as i32, 0),
+            Size::new(width, T::DISPLAY_SIZE.height),
+        )
+    });
+    let scene_area = Rectangle::new(Point::zero(), T::DISPLAY_SIZE);
+
+    let mut display = SimulatorDisplay::new(
+        T::DISPLAY_SIZE + Size::new(menu_panel.map_or(0, |panel| panel.size.width), 0),
+    );
+    let mut menu = Menu::new();
+    let mut show_menu = true;
+    let mut inspector = Inspector::new();
+    let mut viewport = Viewport::new(T::DISPLAY_SIZE);
+    let mut show_statistics = false;
+    let mut show_overdraw = false;
+    let mut show_ideal_geometry = false;
+    let mut show_diff = false;
+    let mut snapshot: Option<T> = None;
+
+    loop {
+        let start = Instant::now();
+
+        let visible_area = viewport.visible_area();
+        let mut scene = SimulatorDisplay::new(visible_area.size);
+        let statistics = draw_app(
+            &app,
+            &mut scene.translated(-visible_area.top_left),
+            inspector.position(),
+        );
+        inspector.update(
+            &scene,
+            visible_area.top_left,
+            statistics.watched_point_method,
+        );
+
+        let overdraw = show_overdraw.then(|| {
+            let mut overdraw = OverdrawDrawTarget::new(visible_area);
+            app.draw(&mut overdraw).unwrap();
+
+            let mut target = scene.translated(-visible_area.top_left);
+            target.clear(app.clear_color()).unwrap();
+            overdraw.draw_heatmap(&mut target).unwrap();
+
+            overdraw
+        });
+
+        let diff = show_diff.then(|| {
+            let mut a = OverdrawDrawTarget::new(visible_area);
+            app.draw(&mut a).unwrap();
+
+            let mut b = OverdrawDrawTarget::new(visible_area);
+            let reference = match app.draw_reference(&mut b) {
+                Some(result) => {
+                    result.unwrap();
+                    "reference"
+                }
+                None => {
+                    if let Some(snapshot) = &snapshot {
+                        snapshot.draw(&mut b).unwrap();
+                    }
+                    "snapshot"
+                }
+            };
+
+            let diff = PixelDiff::new(&a, &b);
+
+            let mut target = scene.translated(-visible_area.top_left);
+            target.clear(app.clear_color()).unwrap();
+            diff.draw(&mut target).unwrap();
+
+            (diff, reference)
+        });
+        viewport.draw_scene(&scene, &mut display);
+        viewport.draw_grid(&mut display);
+
+        if show_ideal_geometry {
+            viewport.draw_outlines(&app.ideal_geometry(), &mut display);
+        }
+
+        if show_statistics {
+            viewport.draw_areas(
+                &statistics.fill_contiguous_areas,
+                Rgb888::CSS_ORANGE.into(),
+                &mut display,
+            );
+            viewport.draw_areas(
+                &statistics.fill_solid_areas,
+                Rgb888::CSS_DODGER_BLUE.into(),
+                &mut display,
+            );
+        }
+
         let menu_color = app.menu_color();
+        if let Some(panel) = &menu_panel {
+            let mut panel_target = display.cropped(panel);
+            panel_target.clear(app.clear_color()).unwrap();
 
-        loop {
-            let start = Instant::now();
+            let separator = Rectangle::new(Point::zero(), Size::new(1, panel.size.height));
+            panel_target.fill_solid(&separator, menu_color).unwrap();
 
-            display.clear(app.clear_color()).unwrap();
+            if show_menu {
+                menu.draw_menu(&app.parameters(), &mut panel_target, menu_color)
+                    .unwrap();
+            }
+        } else if show_menu {
+            menu.draw_menu(&app.parameters(), &mut display, menu_color)
+                .unwrap();
+        }
 
-            app.draw(&mut display).unwrap();
+        let mut status = Vec::new();
+        status.extend(app.status());
+        status.extend(inspector.status());
+        if show_statistics {
+            status.push(statistics.to_string());
+        }
+        if let Some(overdraw) = &overdraw {
+            status.push(format!(
+                "overdrawn: {}px, max: {}x",
+                overdraw.overdrawn_points(),
+                overdraw.max_count()
+            ));
+        }
+        if let Some((diff, reference)) = &diff {
+            status.push(format!("A: app, B: {}\n{}", reference, diff));
+        }
 
-            let mut parameters = app.parameters();
+        status::draw_status_panel(
+            &status,
+            &mut display.cropped(&scene_area),
+            menu_color,
+            app.clear_color(),
+        )
+        .unwrap();
+        viewport
+            .draw_status(
+                &mut display.cropped(&scene_area),
+                menu_color,
+                app.clear_color(),
+            )
+            .unwrap();
 
-            menu.draw_menu(&parameters, &mut display, menu_color)
+        if let Some(picker) = &picker {
+            picker
+                .draw(&mut display, app.menu_color(), app.clear_color())
                 .unwrap();
+        }
+
+        window.update(&display);
+
+        let mut parameters = app.parameters();
+
+        for event in window.events() {
+            if let SimulatorEvent::Quit = event {
+                return None;
+            }
 
-            window.update(&display);
+            if let Some(picker) = picker.as_deref_mut() {
+                match picker.handle_event(&event) {
+                    PickerEvent::Ignored => {}
+                    PickerEvent::Handled => continue,
+                    PickerEvent::Selected(index) => return Some(index),
+                }
+            }
+
+            if menu.is_editing() {
+                menu.handle_event(&mut parameters, event);
+                continue;
+            }
+
+            let menu_offset = menu_panel.map_or(Point::zero(), |panel| panel.top_left);
+            if show_menu && menu.handle_mouse_event(&mut parameters, &event, menu_offset) {
+                continue;
+            }
+
+            if let Some(point) = mouse_position(&event) {
+                if !scene_area.contains(point) {
+                    continue;
+                }
+            }
 
-            if menu.handle_events(&mut parameters, &mut window) {
-                break;
+            // Digits start the text entry if an editable parameter is selected. `0` and `-` are
+            // left to the viewport.
+            if show_menu && menu.start_text_entry(&parameters, &event) {
+                continue;
             }
 
-            let frame_duration = start.elapsed();
-            if frame_duration < MIN_FRAME_DURATION {
-                std::thread::sleep(MIN_FRAME_DURATION - frame_duration);
+            if viewport.handle_event(&event) {
+                continue;
             }
+
+            let event = viewport.map_event(event);
+
+            if inspector.handle_event(&event) {
+                continue;
+            }
+
+            match event {
+                SimulatorEvent::KeyDown {
+                    keycode: Keycode::S,
+                    keymod,
+                    ..
+                } if is_ctrl(keymod) => match parameter_file::save(&parameters, &parameter_file) {
+                    Ok(()) => println!("Saved parameters to \"{}\"", parameter_file.display()),
+                    Err(e) => eprintln!("error: {}", e),
+                },
+                SimulatorEvent::KeyDown {
+                    keycode: Keycode::L,
+                    keymod,
+                    ..
+                } if is_ctrl(keymod) => {
+                    match parameter_file::load(&mut parameters, &parameter_file) {
+                        Ok(()) => {
+                            println!("Loaded parameters from \"{}\"", parameter_file.display())
+                        }
+                        Err(e) => eprintln!("error: {}", e),
+                    }
+                }
+                SimulatorEvent::KeyDown {
+                    keycode: Keycode::C,
+                    ..
+                } => show_statistics ^= true,
+                SimulatorEvent::KeyDown {
+                    keycode: Keycode::H,
+                    ..
+                } => show_overdraw ^= true,
+                SimulatorEvent::KeyDown {
+                    keycode: Keycode::O,
+                    ..
+                } => show_ideal_geometry ^= true,
+                SimulatorEvent::KeyDown {
+                    keycode: Keycode::M,
+                    ..
+                } => show_menu ^= true,
+                SimulatorEvent::KeyDown {
+                    keycode: Keycode::D,
+                    ..
+                } => {
+                    show_diff ^= true;
+                    snapshot = show_diff.then(|| snapshot_app(&parameters));
+                }
+                event => menu.handle_event(&mut parameters, event),
+            }
+        }
+
+        let frame_duration = start.elapsed();
+        if frame_duration < MIN_FRAME_DURATION {
+            std::thread::sleep(MIN_FRAME_DURATION - frame_duration);
+        }
+    }
+}
+
+/// Clears the display and draws the app with its underlays and overlays.
+///
+/// Returns the statistics of the draw calls made by the app. If `watched_point` is set, the
+/// statistics will also contain the method which was used to draw this point.
+fn draw_app<T, D>(app: &T, display: &mut D, watched_point: Option<Point>) -> DrawStatistics
+where
+    T: App,
+    D: DrawTarget<Color = T::Color, Error = Infallible>,
+{
+    display.clear(app.clear_color()).unwrap();
+    app.draw_underlay(display).unwrap();
+
+    let mut target = StatisticsDrawTarget::new(display);
+    if let Some(point) = watched_point {
+        target.watch(point);
+    }
+    app.draw(&mut target).unwrap();
+    let statistics = target.into_statistics();
+
+    app.draw_overlay(display).unwrap();
+
+    statistics
+}
+
+/// Clears the display and draws the app and the optional menu.
+fn draw_frame<T: App>(
+    app: &mut T,
+    menu: Option<&mut Menu>,
+    display: &mut SimulatorDisplay<T::Color>,
+) {
+    draw_app(app, display, None);
+
+    if let Some(menu) = menu {
+        let menu_color = app.menu_color();
+
+        menu.draw_menu(&app.parameters(), display, menu_color)
+            .unwrap();
+    }
+}
+
+/// Creates a new instance of an app with the given parameter values.
+fn snapshot_app<T: App>(parameters: &[Parameter]) -> T {
+    let mut app = T::new();
+    parameter_file::apply(
+        &mut app.parameters(),
+        &parameter_file::to_string(parameters),
+    )
+    .expect("parameters of a new app instance should match");
+
+    app
+}
+
+/// Returns the position of the mouse cursor for mouse move and mouse button down events.
+fn mouse_position(event: &SimulatorEvent) -> Option<Point> {
+    match *event {
+        SimulatorEvent::MouseMove { point } | SimulatorEvent::MouseButtonDown { point, .. } => {
+            Some(point)
         }
+        _ => None,
     }
 }
+
+/// Returns `true` if one of the control keys is pressed.
+fn is_ctrl(keymod: Mod) -> bool {
+    keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD)
+}
+
+/// Returns the file name of the executable without the extension.
+fn executable_name() -> String {
+    env::current_exe()
+        .ok()
+        .and_then(|path| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| "app".to_string())
+}
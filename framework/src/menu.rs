@@ -2,20 +2,58 @@ use embedded_graphics::{
     geometry::AnchorPoint,
     mono_font::{iso_8859_1::FONT_6X10, MonoTextStyle, MonoTextStyleBuilder},
     prelude::*,
-    primitives::{Line, PrimitiveStyle, Rectangle},
+    primitives::{Line, PrimitiveStyle, Rectangle, Triangle},
     text::Text,
 };
 use embedded_graphics_simulator::{
-    sdl2::{Keycode, MouseButton},
-    SimulatorEvent, Window,
+    sdl2::{Keycode, Mod, MouseButton, MouseWheelDirection},
+    SimulatorEvent,
 };
 
 use crate::{parameter::Value, Parameter};
 
+/// Height of a single menu item.
+const ITEM_HEIGHT: i32 = 10;
+
+/// Width of the value column in the menu panel, in characters.
+const MAX_VALUE_WIDTH: u32 = 12;
+
+/// Horizontal mouse movement which changes a value by one step while scrubbing.
+const SCRUB_DISTANCE: i32 = 4;
+
 pub struct Menu {
     selected: usize,
     active: bool,
     mouse_button_down: bool,
+    /// Index of the first visible parameter if the menu doesn't fit on the display.
+    scroll: usize,
+    /// Layout of the visible items, which is updated when the menu is drawn.
+    items: Vec<ItemLayout>,
+    /// Mouse position in menu coordinates.
+    mouse_position: Point,
+    /// Index of the scrubbed parameter and the x coordinate of the last step.
+    scrub: Option<(usize, i32)>,
+    /// Number of key repeats since the last key was pressed.
+    repeats: u32,
+    /// Text entry for the selected parameter.
+    text_entry: Option<TextEntry>,
+}
+
+/// Text which is entered to replace the value of the selected parameter.
+struct TextEntry {
+    text: String,
+    /// The text couldn't be parsed.
+    invalid: bool,
+}
+
+/// Position of a visible menu item.
+struct ItemLayout {
+    /// Parameter index.
+    index: usize,
+    /// Area of the whole item.
+    area: Rectangle,
+    /// Area of the value or checkbox.
+    value_area: Rectangle,
 }
 
 impl Menu {
@@ -24,11 +62,46 @@ impl Menu {
             selected: 0,
             active: false,
             mouse_button_down: false,
+            scroll: 0,
+            items: Vec::new(),
+            mouse_position: Point::zero(),
+            scrub: None,
+            repeats: 0,
+            text_entry: None,
         }
     }
 
+    /// Returns `true` if a value is currently being entered.
+    ///
+    /// All events should be passed to the menu while a value is entered.
+    pub(crate) fn is_editing(&self) -> bool {
+        self.text_entry.is_some()
+    }
+
+    /// Returns the width of a side panel which fits the menu.
+    pub(crate) fn panel_width(parameters: &[Parameter]) -> u32 {
+        let max_name_width = parameters
+            .iter()
+            .map(|parameter| parameter.name.len())
+            .max()
+            .unwrap_or(0);
+
+        // Leave enough space for values like `(-100, -100)` and for longer values, like
+        // rectangles, to grow while they are edited.
+        let max_value_width = parameters
+            .iter()
+            .map(|parameter| parameter.value.to_string().chars().count() as u32 + 4)
+            .fold(MAX_VALUE_WIDTH, u32::max);
+
+        (max_name_width as u32 + 1 + max_value_width) * 6 + 8 + 4
+    }
+
+    /// Draws the menu.
+    ///
+    /// If the parameters don't fit on the display the menu is scrolled to keep the selected
+    /// parameter visible and indicators are shown above and below the visible parameters.
     pub(crate) fn draw_menu<T>(
-        &self,
+        &mut self,
         parameters: &[Parameter],
         target: &mut T,
         color: T::Color,
@@ -52,8 +125,36 @@ impl Menu {
             .build();
 
         let mut position = Point::new(2, 8);
+        self.items.clear();
+
+        let rows = (target.bounding_box().size.height as i32 / ITEM_HEIGHT) as usize;
+        let visible = if parameters.len() > rows {
+            // Reserve the first and last row for the scroll indicators.
+            let count = rows.saturating_sub(2).max(1);
+
+            if self.selected < self.scroll {
+                self.scroll = self.selected;
+            } else if self.selected >= self.scroll + count {
+                self.scroll = self.selected + 1 - count;
+            }
+            self.scroll = self.scroll.min(parameters.len() - count);
+
+            draw_scroll_indicator(self.scroll, true, position, color, target)?;
+            position.y += ITEM_HEIGHT;
 
-        for (index, parameter) in parameters.iter().enumerate() {
+            self.scroll..self.scroll + count
+        } else {
+            self.scroll = 0;
+
+            0..parameters.len()
+        };
+
+        for (index, parameter) in parameters
+            .iter()
+            .enumerate()
+            .take(visible.end)
+            .skip(visible.start)
+        {
             let item_style = if index == self.selected && self.active {
                 style_inverted
             } else {
@@ -65,7 +166,7 @@ impl Menu {
             }
 
             Text::new(&parameter.name, position + name_delta, item_style).draw(target)?;
-            match &parameter.value {
+            let value_area = match &parameter.value {
                 Value::Bool(value) => {
                     let rect = Rectangle::new(
                         position + value_delta - Point::new(0, 7),
@@ -73,88 +174,407 @@ impl Menu {
                     );
 
                     Checkbox::new(**value, rect, color).draw(target)?;
+
+                    rect
                 }
                 _ => {
-                    Text::new(&parameter.value.to_string(), position + value_delta, style)
-                        .draw(target)?;
+                    let (text, value_style) = match &self.text_entry {
+                        Some(entry) if index == self.selected => {
+                            let invalid = if entry.invalid { " invalid" } else { "" };
+
+                            (format!("{}_{}", entry.text, invalid), style_inverted)
+                        }
+                        _ => (parameter.value.to_string(), style),
+                    };
+                    Text::new(&text, position + value_delta, value_style).draw(target)?;
+
+                    Rectangle::new(
+                        position + value_delta - Point::new(0, 8),
+                        Size::new(text.chars().count() as u32 * 6, ITEM_HEIGHT as u32),
+                    )
                 }
-            }
+            };
+
+            let item_top_left = Point::new(0, position.y - 8);
+            self.items.push(ItemLayout {
+                index,
+                area: Rectangle::new(
+                    item_top_left,
+                    Size::new(
+                        (value_area.top_left.x - item_top_left.x) as u32 + value_area.size.width,
+                        ITEM_HEIGHT as u32,
+                    ),
+                ),
+                value_area,
+            });
 
-            position.y += 10;
+            position.y += ITEM_HEIGHT;
+        }
+
+        if visible.len() < parameters.len() {
+            let hidden = parameters.len() - visible.end;
+            draw_scroll_indicator(hidden, false, position, color, target)?;
         }
 
         Ok(())
     }
 
-    pub(crate) fn handle_events(
+    /// Handles mouse events which interact with the menu items.
+    ///
+    /// Clicking an item selects it, clicking a checkbox toggles it and numeric values can be
+    /// changed by using the mouse wheel or by dragging horizontally. The `offset` is the position
+    /// of the menu in the event coordinates. Returns `true` if the event was used by the menu.
+    pub(crate) fn handle_mouse_event(
         &mut self,
         parameters: &mut [Parameter],
-        window: &mut Window,
+        event: &SimulatorEvent,
+        offset: Point,
     ) -> bool {
-        for event in window.events() {
-            let event = match event {
-                SimulatorEvent::Quit => return true,
-                SimulatorEvent::KeyDown { keycode, .. } => match keycode {
-                    Keycode::Up => Event::Up,
-                    Keycode::Down => Event::Down,
-                    Keycode::Left => Event::Left,
-                    Keycode::Right => Event::Right,
-                    Keycode::Space | Keycode::Return => Event::Activate,
-                    _ => continue,
-                },
-                SimulatorEvent::MouseButtonDown { mouse_btn, point }
-                    if mouse_btn == MouseButton::Left =>
-                {
-                    self.mouse_button_down = true;
-                    Event::MouseMove(point)
+        match *event {
+            SimulatorEvent::MouseButtonDown {
+                mouse_btn: MouseButton::Left,
+                point,
+            } => {
+                let point = point - offset;
+                let (index, in_value) = match self.item_at(point) {
+                    Some(item) => (item.index, item.value_area.contains(point)),
+                    None => return false,
+                };
+
+                self.selected = index;
+                self.active = false;
+
+                if in_value {
+                    match &mut parameters[index].value {
+                        Value::Bool(value) => **value ^= true,
+                        Value::Choice(choice) => choice.next(),
+                        value if value.is_numeric() => self.scrub = Some((index, point.x)),
+                        _ => {}
+                    }
                 }
-                SimulatorEvent::MouseButtonDown { mouse_btn, .. }
-                    if mouse_btn == MouseButton::Middle =>
-                {
-                    Event::Activate
+
+                true
+            }
+            SimulatorEvent::MouseMove { point } => {
+                self.mouse_position = point - offset;
+
+                let (index, last_x) = match self.scrub {
+                    Some(scrub) => scrub,
+                    None => return false,
+                };
+
+                let steps = (self.mouse_position.x - last_x) / SCRUB_DISTANCE;
+                let event = if steps > 0 {
+                    Event::Right(Modifiers::default())
+                } else {
+                    Event::Left(Modifiers::default())
+                };
+                for _ in 0..steps.abs() {
+                    parameters[index].handle_event(event);
                 }
-                SimulatorEvent::MouseMove { point } if self.mouse_button_down => {
-                    Event::MouseMove(point)
+
+                self.scrub = Some((index, last_x + steps * SCRUB_DISTANCE));
+
+                true
+            }
+            SimulatorEvent::MouseButtonUp {
+                mouse_btn: MouseButton::Left,
+                ..
+            } if self.scrub.is_some() => {
+                self.scrub = None;
+
+                true
+            }
+            SimulatorEvent::MouseWheel {
+                scroll_delta,
+                direction,
+            } => {
+                let index = match self.item_at(self.mouse_position) {
+                    Some(item) if item.value_area.contains(self.mouse_position) => item.index,
+                    _ => return false,
+                };
+
+                if !parameters[index].value.is_numeric() {
+                    return false;
                 }
-                SimulatorEvent::MouseButtonUp { .. } => {
-                    self.mouse_button_down = false;
-                    continue;
+
+                let delta = match direction {
+                    MouseWheelDirection::Flipped => -scroll_delta.y,
+                    _ => scroll_delta.y,
+                };
+                let event = if delta > 0 {
+                    Event::Up(Modifiers::default())
+                } else {
+                    Event::Down(Modifiers::default())
+                };
+                for _ in 0..delta.abs() {
+                    parameters[index].handle_event(event);
                 }
-                _ => continue,
-            };
 
-            match event {
-                Event::Up if !self.active => {
-                    if self.selected > 0 {
-                        self.selected -= 1;
-                    } else {
-                        self.selected = parameters.len() - 1;
-                    }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Starts the text entry for the selected parameter.
+    ///
+    /// Typing a digit from `1` to `9` starts the entry with this digit and pressing `E` starts an
+    /// empty entry. `0` and `-` don't start an entry, because they are used to reset and zoom the
+    /// viewport, but they can be typed once the entry was started, e.g. `E` followed by `-4,120`.
+    /// Returns `true` if the text entry was started.
+    pub(crate) fn start_text_entry(
+        &mut self,
+        parameters: &[Parameter],
+        event: &SimulatorEvent,
+    ) -> bool {
+        let keycode = match event {
+            SimulatorEvent::KeyDown { keycode, .. } if self.text_entry.is_none() => *keycode,
+            _ => return false,
+        };
+
+        let text = match (keycode, text_entry_char(keycode)) {
+            (Keycode::E, _) => String::new(),
+            (_, Some(c @ '1'..='9')) => c.to_string(),
+            _ => return false,
+        };
+
+        if !parameters
+            .get(self.selected)
+            .is_some_and(|parameter| parameter.value.is_editable())
+        {
+            return false;
+        }
+
+        self.text_entry = Some(TextEntry {
+            text,
+            invalid: false,
+        });
+
+        true
+    }
+
+    /// Handles key presses for the text entry.
+    ///
+    /// The entered text replaces the value if `Enter` is pressed and `Escape` cancels the entry.
+    /// Returns `true` if the key press was used.
+    fn handle_text_entry(&mut self, parameters: &mut [Parameter], keycode: Keycode) -> bool {
+        let entry = match &mut self.text_entry {
+            Some(entry) => entry,
+            None => return false,
+        };
+
+        match keycode {
+            Keycode::Return | Keycode::KpEnter => {
+                match parameters[self.selected].parse(&entry.text) {
+                    Ok(()) => self.text_entry = None,
+                    Err(_) => entry.invalid = true,
                 }
-                Event::Down if !self.active => {
-                    self.selected += 1;
-                    if self.selected >= parameters.len() {
-                        self.selected = 0;
-                    }
+            }
+            Keycode::Escape => self.text_entry = None,
+            Keycode::Backspace => {
+                entry.text.pop();
+                entry.invalid = false;
+            }
+            keycode => {
+                if let Some(c) = text_entry_char(keycode) {
+                    entry.text.push(c);
+                    entry.invalid = false;
                 }
-                Event::Activate => self.active ^= true,
-                _ => parameters[self.selected].value.handle_event(event),
             }
         }
 
-        false
+        true
     }
+
+    /// Returns the visible item at the given point in menu coordinates.
+    fn item_at(&self, point: Point) -> Option<&ItemLayout> {
+        self.items.iter().find(|item| item.area.contains(point))
+    }
+
+    /// Handles keyboard events and dragging in the scene.
+    ///
+    /// The selected parameter can also be changed by these events while the menu is hidden.
+    pub(crate) fn handle_event(&mut self, parameters: &mut [Parameter], event: SimulatorEvent) {
+        if let SimulatorEvent::KeyDown { keycode, .. } = event {
+            if self.handle_text_entry(parameters, keycode) {
+                return;
+            }
+        }
+
+        if self.is_editing() {
+            return;
+        }
+
+        let event = match event {
+            SimulatorEvent::KeyDown {
+                keycode,
+                keymod,
+                repeat,
+            } => {
+                self.repeats = if repeat { self.repeats + 1 } else { 0 };
+                let modifiers = Modifiers::new(keymod, self.repeats);
+
+                match keycode {
+                    Keycode::Up => Event::Up(modifiers),
+                    Keycode::Down => Event::Down(modifiers),
+                    Keycode::Left => Event::Left(modifiers),
+                    Keycode::Right => Event::Right(modifiers),
+                    Keycode::Space | Keycode::Return => Event::Activate,
+                    _ => return,
+                }
+            }
+            SimulatorEvent::MouseButtonDown { mouse_btn, point }
+                if mouse_btn == MouseButton::Left =>
+            {
+                self.mouse_button_down = true;
+                Event::MouseMove(point)
+            }
+            SimulatorEvent::MouseButtonDown { mouse_btn, .. }
+                if mouse_btn == MouseButton::Middle =>
+            {
+                Event::Activate
+            }
+            SimulatorEvent::MouseMove { point } if self.mouse_button_down => {
+                Event::MouseMove(point)
+            }
+            SimulatorEvent::MouseButtonUp { .. } => {
+                self.mouse_button_down = false;
+                return;
+            }
+            _ => return,
+        };
+
+        if parameters.is_empty() {
+            return;
+        }
+
+        match event {
+            Event::Up(_) if !self.active => {
+                if self.selected > 0 {
+                    self.selected -= 1;
+                } else {
+                    self.selected = parameters.len() - 1;
+                }
+            }
+            Event::Down(_) if !self.active => {
+                self.selected += 1;
+                if self.selected >= parameters.len() {
+                    self.selected = 0;
+                }
+            }
+            Event::Activate => self.active ^= true,
+            _ => parameters[self.selected].handle_event(event),
+        }
+    }
+}
+
+/// Returns the character which is entered by a key in the text entry.
+fn text_entry_char(keycode: Keycode) -> Option<char> {
+    Some(match keycode {
+        Keycode::Num0 | Keycode::Kp0 => '0',
+        Keycode::Num1 | Keycode::Kp1 => '1',
+        Keycode::Num2 | Keycode::Kp2 => '2',
+        Keycode::Num3 | Keycode::Kp3 => '3',
+        Keycode::Num4 | Keycode::Kp4 => '4',
+        Keycode::Num5 | Keycode::Kp5 => '5',
+        Keycode::Num6 | Keycode::Kp6 => '6',
+        Keycode::Num7 | Keycode::Kp7 => '7',
+        Keycode::Num8 | Keycode::Kp8 => '8',
+        Keycode::Num9 | Keycode::Kp9 => '9',
+        Keycode::Minus | Keycode::KpMinus => '-',
+        Keycode::Comma | Keycode::KpComma => ',',
+        Keycode::Period | Keycode::KpPeriod => '.',
+        Keycode::X => 'x',
+        _ => return None,
+    })
+}
+
+/// Draws an indicator for the number of hidden parameters above or below the visible part of the
+/// menu.
+///
+/// Nothing is drawn if no parameters are hidden.
+fn draw_scroll_indicator<T>(
+    hidden: usize,
+    up: bool,
+    position: Point,
+    color: T::Color,
+    target: &mut T,
+) -> Result<(), T::Error>
+where
+    T: DrawTarget,
+{
+    if hidden == 0 {
+        return Ok(());
+    }
+
+    let (tip, base) = if up { (-7, -2) } else { (-1, -6) };
+
+    Triangle::new(
+        position + Point::new(2, tip),
+        position + Point::new(-1, base),
+        position + Point::new(5, base),
+    )
+    .into_styled(PrimitiveStyle::with_fill(color))
+    .draw(target)?;
+
+    Text::new(
+        &format!("{} more", hidden),
+        position + Point::new(12, 0),
+        MonoTextStyle::new(&FONT_6X10, color),
+    )
+    .draw(target)?;
+
+    Ok(())
 }
 
+#[derive(Debug, Clone, Copy)]
 pub(crate) enum Event {
-    Up,
-    Down,
-    Left,
-    Right,
+    Up(Modifiers),
+    Down(Modifiers),
+    Left(Modifiers),
+    Right(Modifiers),
     Activate,
     MouseMove(Point),
 }
 
+/// Modifiers which determine the step size of numeric values.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct Modifiers {
+    /// One of the shift keys is pressed.
+    pub shift: bool,
+    /// One of the control keys is pressed.
+    pub ctrl: bool,
+    /// Number of key repeats while the key is held down.
+    pub repeats: u32,
+}
+
+impl Modifiers {
+    pub(crate) fn new(keymod: Mod, repeats: u32) -> Self {
+        Self {
+            shift: keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD),
+            ctrl: crate::is_ctrl(keymod),
+            repeats,
+        }
+    }
+
+    /// Returns the step size.
+    ///
+    /// The step size is `1` by default, `10` if shift is pressed and `100` if control is pressed.
+    /// Holding down a key accelerates the change by increasing the step size up to ten times.
+    pub(crate) fn step(self) -> u32 {
+        let base = if self.ctrl {
+            100
+        } else if self.shift {
+            10
+        } else {
+            1
+        };
+
+        base * (1 + self.repeats / 10).min(10)
+    }
+}
+
 struct Checkbox<C> {
     value: bool,
     rect: Rectangle,
@@ -200,3 +620,147 @@ impl<C: PixelColor> Drawable for Checkbox<C> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use embedded_graphics_simulator::SimulatorDisplay;
+
+    use super::*;
+    use crate::viewport::Viewport;
+
+    fn key_down(keycode: Keycode) -> SimulatorEvent {
+        SimulatorEvent::KeyDown {
+            keycode,
+            keymod: Mod::NOMOD,
+            repeat: false,
+        }
+    }
+
+    fn visible_items(menu: &Menu) -> Vec<usize> {
+        menu.items.iter().map(|item| item.index).collect()
+    }
+
+    #[test]
+    fn scroll_to_selected_parameter() {
+        let mut values = [false; 10];
+        let names = (0..values.len())
+            .map(|index| format!("p{}", index))
+            .collect::<Vec<_>>();
+        let mut parameters = values
+            .iter_mut()
+            .zip(names.iter())
+            .map(|(value, name)| Parameter::new(name, value))
+            .collect::<Vec<_>>();
+
+        // The display fits 5 rows, 2 of which are used by the scroll indicators.
+        let mut display = SimulatorDisplay::<BinaryColor>::new(Size::new(100, 50));
+        let mut menu = Menu::new();
+
+        menu.draw_menu(&parameters, &mut display, BinaryColor::On)
+            .unwrap();
+        assert_eq!(visible_items(&menu), [0, 1, 2]);
+
+        for _ in 0..5 {
+            menu.handle_event(&mut parameters, key_down(Keycode::Down));
+        }
+        menu.draw_menu(&parameters, &mut display, BinaryColor::On)
+            .unwrap();
+        assert_eq!(menu.selected, 5);
+        assert_eq!(visible_items(&menu), [3, 4, 5]);
+
+        for _ in 0..2 {
+            menu.handle_event(&mut parameters, key_down(Keycode::Up));
+        }
+        menu.draw_menu(&parameters, &mut display, BinaryColor::On)
+            .unwrap();
+        assert_eq!(visible_items(&menu), [3, 4, 5]);
+
+        menu.handle_event(&mut parameters, key_down(Keycode::Up));
+        menu.draw_menu(&parameters, &mut display, BinaryColor::On)
+            .unwrap();
+        assert_eq!(visible_items(&menu), [2, 3, 4]);
+
+        // Selecting the previous item of the first parameter wraps around to the last parameter.
+        for _ in 0..3 {
+            menu.handle_event(&mut parameters, key_down(Keycode::Up));
+        }
+        menu.draw_menu(&parameters, &mut display, BinaryColor::On)
+            .unwrap();
+        assert_eq!(menu.selected, 9);
+        assert_eq!(visible_items(&menu), [7, 8, 9]);
+    }
+
+    #[test]
+    fn zoom_keys_and_text_entry() {
+        let mut point = Point::new(1, 2);
+        let mut parameters = vec![Parameter::new("point", &mut point)];
+        let mut menu = Menu::new();
+        let mut viewport = Viewport::new(Size::new(64, 64));
+
+        // `-` and `0` don't start the text entry and are handled by the viewport.
+        viewport.handle_event(&key_down(Keycode::Plus));
+        for keycode in [
+            Keycode::Minus,
+            Keycode::KpMinus,
+            Keycode::Num0,
+            Keycode::Kp0,
+        ] {
+            assert!(!menu.start_text_entry(&parameters, &key_down(keycode)));
+        }
+        assert!(viewport.handle_event(&key_down(Keycode::Minus)));
+        assert_eq!(viewport.visible_area().size, Size::new(64, 64));
+        assert!(viewport.handle_event(&key_down(Keycode::Num0)));
+        assert!(!menu.is_editing());
+
+        // Other digits start the text entry.
+        assert!(menu.start_text_entry(&parameters, &key_down(Keycode::Num7)));
+        menu.handle_event(&mut parameters, key_down(Keycode::Escape));
+        assert!(!menu.is_editing());
+
+        // `-` and `0` can be typed once the text entry was started.
+        assert!(menu.start_text_entry(&parameters, &key_down(Keycode::E)));
+        for keycode in [
+            Keycode::Minus,
+            Keycode::Num4,
+            Keycode::Comma,
+            Keycode::Num1,
+            Keycode::Num2,
+            Keycode::Num0,
+            Keycode::Return,
+        ] {
+            menu.handle_event(&mut parameters, key_down(keycode));
+        }
+        assert!(!menu.is_editing());
+
+        drop(parameters);
+        assert_eq!(point, Point::new(-4, 120));
+    }
+
+    #[test]
+    fn no_parameters() {
+        let mut parameters = Vec::new();
+        let mut display = SimulatorDisplay::<BinaryColor>::new(Size::new(100, 50));
+        let mut menu = Menu::new();
+
+        for keycode in [Keycode::Up, Keycode::Down, Keycode::Left, Keycode::Space] {
+            menu.handle_event(&mut parameters, key_down(keycode));
+        }
+        assert!(!menu.start_text_entry(&parameters, &key_down(Keycode::E)));
+
+        let click = SimulatorEvent::MouseButtonDown {
+            mouse_btn: MouseButton::Left,
+            point: Point::new(10, 5),
+        };
+        assert!(!menu.handle_mouse_event(&mut parameters, &click, Point::zero()));
+        menu.handle_event(&mut parameters, click);
+
+        menu.draw_menu(&parameters, &mut display, BinaryColor::On)
+            .unwrap();
+        assert!(menu.items.is_empty());
+        assert_eq!(
+            Menu::panel_width(&parameters),
+            (1 + MAX_VALUE_WIDTH) * 6 + 12
+        );
+    }
+}
@@ -1,12 +1,16 @@
-use embedded_graphics::prelude::*;
-use std::fmt;
+use embedded_graphics::{
+    prelude::*,
+    primitives::{Rectangle, StrokeAlignment},
+};
+use std::{fmt, ops::RangeInclusive, str::FromStr};
 
-use crate::menu::Event;
+use crate::menu::{Event, Modifiers};
 
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Debug, PartialEq)]
 pub struct Parameter<'a> {
     pub(crate) name: String,
     pub(crate) value: Value<'a>,
+    pub(crate) constraints: Constraints,
 }
 
 impl<'a> Parameter<'a> {
@@ -17,58 +21,671 @@ impl<'a> Parameter<'a> {
         Self {
             name: name.to_string(),
             value: Value::from(value),
+            constraints: Constraints::default(),
         }
     }
+
+    /// Creates a parameter which cycles through a list of named options.
+    ///
+    /// The menu shows the name of the selected option. Values can be set by name, e.g. by using
+    /// `--set alignment=Inside`. If the current value isn't one of the options it is replaced by
+    /// the first option. Use [`choice_index`](Self::choice_index) for types which don't implement
+    /// `PartialEq`, like `MonoFont`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `options` is empty.
+    pub fn choice<T>(name: &str, value: &'a mut T, options: &[(&str, T)]) -> Self
+    where
+        T: Copy + PartialEq + 'a,
+    {
+        assert!(!options.is_empty(), "choice parameters require an option");
+
+        let values = options
+            .iter()
+            .map(|(_, option)| *option)
+            .collect::<Vec<_>>();
+        let selected = values
+            .iter()
+            .position(|option| option == value)
+            .unwrap_or_else(|| {
+                *value = values[0];
+                0
+            });
+        let names = options.iter().map(|(name, _)| name.to_string()).collect();
+
+        Self {
+            name: name.to_string(),
+            value: Value::Choice(Choice {
+                names,
+                selected,
+                select: Box::new(move |index| *value = values[index]),
+            }),
+            constraints: Constraints::default(),
+        }
+    }
+
+    /// Creates a parameter which selects an option by its index.
+    ///
+    /// This works like [`choice`](Self::choice), but the value is the index of the selected
+    /// option in `names`. The app can use the index to look up values which can't be compared.
+    /// An index outside of the options is replaced by `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `names` is empty.
+    pub fn choice_index(name: &str, index: &'a mut usize, names: &[&str]) -> Self {
+        assert!(!names.is_empty(), "choice parameters require an option");
+
+        if *index >= names.len() {
+            *index = 0;
+        }
+
+        Self {
+            name: name.to_string(),
+            value: Value::Choice(Choice {
+                names: names.iter().map(|name| name.to_string()).collect(),
+                selected: *index,
+                select: Box::new(move |selected| *index = selected),
+            }),
+            constraints: Constraints::default(),
+        }
+    }
+
+    /// Restricts the value to a range.
+    ///
+    /// The range of angles is specified in degrees. For points and sizes the range applies to
+    /// both components. For rectangles it applies to the width and height, but dragging a corner
+    /// with the mouse restricts the coordinates of the corners instead.
+    pub fn range<T: Into<f64>>(mut self, range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        self.constraints.range = Some(start.into()..=end.into());
+
+        self
+    }
+
+    /// Sets the amount by which the value is changed by a single key press.
+    ///
+    /// The step of integer values, points, sizes and rectangles is rounded to the nearest integer,
+    /// but is always at least 1.
+    pub fn step<T: Into<f64>>(mut self, step: T) -> Self {
+        self.constraints.step = step.into();
+
+        self
+    }
+
+    /// Wraps around to the other end of the range if the value is changed past the range.
+    ///
+    /// For angles and floating point values the start and end of the range are treated as the
+    /// same value, like `0°` and `360°`, and the remainder of a step past the end is kept, e.g.
+    /// `355 + 10` becomes `5` in `0..=360`. For integer values the start follows the end, e.g.
+    /// `5 + 1` becomes `0` in `0..=5`.
+    pub fn wrap(mut self) -> Self {
+        self.constraints.wrap = true;
+
+        self
+    }
+
+    /// Returns the parameter name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the constraints of the value.
+    pub fn constraints(&self) -> &Constraints {
+        &self.constraints
+    }
+
+    /// Returns all valid values, formatted as strings.
+    ///
+    /// The range of integer values is limited to the values which can be represented by the
+    /// type, e.g. `0..=255` for `u8`. Returns `None` if the set of valid values isn't known,
+    /// e.g. because a numeric value has no range, or if there are more than
+    /// [`MAX_VALID_VALUES`] values.
+    pub fn valid_values(&self) -> Option<Vec<String>> {
+        // Returns the number of steps in a range, or `None` if there are too many steps.
+        let count = |start: f64, end: f64, step: f64| {
+            if end < start {
+                return Some(0);
+            }
+
+            let count = ((end - start) / step).floor() + 1.0;
+            (count <= MAX_VALID_VALUES as f64).then_some(count as usize)
+        };
+
+        match &self.value {
+            Value::Bool(_) => Some(vec!["false".to_string(), "true".to_string()]),
+            Value::U8(_) | Value::U32(_) | Value::I32(_) => {
+                let range = self.constraints.range.as_ref()?;
+                let step = self.constraints.integer_step();
+
+                let (min, max) = match self.value {
+                    Value::U8(_) => (f64::from(u8::MIN), f64::from(u8::MAX)),
+                    Value::U32(_) => (f64::from(u32::MIN), f64::from(u32::MAX)),
+                    _ => (f64::from(i32::MIN), f64::from(i32::MAX)),
+                };
+                let start = range.start().max(min).ceil();
+                let end = range.end().min(max);
+
+                let values = (0..count(start, end, step)?)
+                    .map(|i| ((start + i as f64 * step) as i64).to_string())
+                    .collect();
+
+                Some(values)
+            }
+            Value::F32(_) | Value::Angle(_) => {
+                let range = self.constraints.range.as_ref()?;
+                let step = self.constraints.step;
+                if step <= 0.0 {
+                    return None;
+                }
+
+                let values = (0..count(*range.start(), *range.end(), step)?)
+                    .map(|i| ((range.start() + i as f64 * step) as f32).to_string())
+                    .collect();
+
+                Some(values)
+            }
+            Value::Choice(choice) => Some(choice.names.clone()),
+            Value::Point(_) | Value::Size(_) | Value::Rectangle(_) => None,
+        }
+    }
+
+    pub(crate) fn handle_event(&mut self, event: Event) {
+        self.value.handle_event(event, &self.constraints);
+    }
+
+    /// Sets the value by parsing a string.
+    ///
+    /// Returns an error if the string couldn't be parsed or if the value is outside of the range.
+    /// The value is left unchanged if an error is returned.
+    pub(crate) fn parse(&mut self, value: &str) -> Result<(), String> {
+        let previous = self.value.save();
+        self.value.parse(value)?;
+
+        if let Some(range) = &self.constraints.range {
+            if !self.value.is_in_range(range) {
+                self.value.restore(previous);
+
+                return Err(format!(
+                    "value \"{}\" is outside of the range {}..={}",
+                    value.trim(),
+                    range.start(),
+                    range.end()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Maximum number of values returned by [`Parameter::valid_values`].
+pub const MAX_VALID_VALUES: usize = 100_000;
+
+/// Constraints for a parameter value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraints {
+    /// Range of valid values.
+    pub range: Option<RangeInclusive<f64>>,
+    /// Amount by which the value is changed by a single key press.
+    pub step: f64,
+    /// Wrap around to the other end of the range.
+    pub wrap: bool,
+}
+
+impl Constraints {
+    /// Applies the constraints to a changed continuous value.
+    ///
+    /// The end of the range wraps around to the start, like `360°` to `0°`.
+    fn apply(&self, value: f64) -> f64 {
+        self.constrain(value, 0.0)
+    }
+
+    /// Applies the constraints to a changed integer value.
+    ///
+    /// The end of the range is a separate value, which is followed by the start of the range.
+    fn apply_integer(&self, value: f64) -> f64 {
+        self.constrain(value, 1.0)
+    }
+
+    /// Clamps or wraps a value into the range.
+    ///
+    /// `end_width` is the distance between the end of the range and the start of the next
+    /// period if the value is wrapped.
+    fn constrain(&self, value: f64, end_width: f64) -> f64 {
+        match &self.range {
+            Some(range) if !range.contains(&value) => {
+                let (start, end) = (*range.start(), *range.end());
+                let period = end - start + end_width;
+
+                if self.wrap && period > 0.0 {
+                    start + (value - start).rem_euclid(period)
+                } else {
+                    value.clamp(start, end)
+                }
+            }
+            _ => value,
+        }
+    }
+
+    /// Returns the step for integer values.
+    ///
+    /// Fractional steps are rounded, because the value would otherwise never change if the step
+    /// is less than 1.
+    fn integer_step(&self) -> f64 {
+        self.step.round().max(1.0)
+    }
+}
+
+impl Default for Constraints {
+    fn default() -> Self {
+        Self {
+            range: None,
+            step: 1.0,
+            wrap: false,
+        }
+    }
+}
+
+/// Options for [`Parameter::choice`] which select the stroke alignment.
+pub const STROKE_ALIGNMENTS: [(&str, StrokeAlignment); 3] = [
+    ("Center", StrokeAlignment::Center),
+    ("Outside", StrokeAlignment::Outside),
+    ("Inside", StrokeAlignment::Inside),
+];
+
+/// Selected option of a choice parameter.
+pub struct Choice<'a> {
+    names: Vec<String>,
+    selected: usize,
+    select: Box<dyn FnMut(usize) + 'a>,
+}
+
+impl Choice<'_> {
+    /// Selects the option with the given index.
+    fn select(&mut self, index: usize) {
+        self.selected = index;
+        (self.select)(index);
+    }
+
+    /// Selects the next option.
+    ///
+    /// The first option is selected after the last option.
+    pub(crate) fn next(&mut self) {
+        self.select((self.selected + 1) % self.names.len());
+    }
+
+    /// Selects the previous option.
+    ///
+    /// The last option is selected after the first option.
+    pub(crate) fn previous(&mut self) {
+        let index = self.selected.checked_sub(1).unwrap_or(self.names.len() - 1);
+        self.select(index);
+    }
 }
 
-#[derive(Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+impl fmt::Debug for Choice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Choice")
+            .field("names", &self.names)
+            .field("selected", &self.selected)
+            .finish()
+    }
+}
+
+impl PartialEq for Choice<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.names == other.names && self.selected == other.selected
+    }
+}
+
+#[derive(Debug, PartialEq)]
 pub enum Value<'a> {
+    U8(&'a mut u8),
     U32(&'a mut u32),
     I32(&'a mut i32),
+    F32(&'a mut f32),
+    Angle(&'a mut Angle),
     Point(&'a mut Point),
+    Size(&'a mut Size),
+    Rectangle(&'a mut Rectangle),
     Bool(&'a mut bool),
+    Choice(Choice<'a>),
+}
+
+/// Copy of a value, which can be used to restore the value later.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SavedValue {
+    U8(u8),
+    U32(u32),
+    I32(i32),
+    F32(f32),
+    Angle(Angle),
+    Point(Point),
+    Size(Size),
+    Rectangle(Rectangle),
+    Bool(bool),
+    Choice(usize),
 }
 
 impl<'a> Value<'a> {
-    pub(crate) fn handle_event(&mut self, event: Event) {
+    /// Returns a copy of the value.
+    fn save(&self) -> SavedValue {
         match self {
-            Self::U32(value) => match event {
-                Event::Down | Event::Left => **value = value.saturating_sub(1),
-                Event::Up | Event::Right => **value = value.saturating_add(1),
+            Self::U8(value) => SavedValue::U8(**value),
+            Self::U32(value) => SavedValue::U32(**value),
+            Self::I32(value) => SavedValue::I32(**value),
+            Self::F32(value) => SavedValue::F32(**value),
+            Self::Angle(value) => SavedValue::Angle(**value),
+            Self::Point(value) => SavedValue::Point(**value),
+            Self::Size(value) => SavedValue::Size(**value),
+            Self::Rectangle(value) => SavedValue::Rectangle(**value),
+            Self::Bool(value) => SavedValue::Bool(**value),
+            Self::Choice(choice) => SavedValue::Choice(choice.selected),
+        }
+    }
+
+    /// Restores a value which was returned by [`save`](Self::save).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the saved value has a different type.
+    fn restore(&mut self, saved: SavedValue) {
+        match (self, saved) {
+            (Self::U8(value), SavedValue::U8(saved)) => **value = saved,
+            (Self::U32(value), SavedValue::U32(saved)) => **value = saved,
+            (Self::I32(value), SavedValue::I32(saved)) => **value = saved,
+            (Self::F32(value), SavedValue::F32(saved)) => **value = saved,
+            (Self::Angle(value), SavedValue::Angle(saved)) => **value = saved,
+            (Self::Point(value), SavedValue::Point(saved)) => **value = saved,
+            (Self::Size(value), SavedValue::Size(saved)) => **value = saved,
+            (Self::Rectangle(value), SavedValue::Rectangle(saved)) => **value = saved,
+            (Self::Bool(value), SavedValue::Bool(saved)) => **value = saved,
+            (Self::Choice(choice), SavedValue::Choice(index)) => choice.select(index),
+            (value, saved) => panic!("can't restore {:?} from {:?}", value, saved),
+        }
+    }
+
+    /// Returns `true` if the value can be changed by entering text in the menu.
+    pub(crate) fn is_editable(&self) -> bool {
+        !matches!(self, Self::Bool(_) | Self::Choice(_))
+    }
+
+    /// Returns `true` if the value is a number.
+    pub(crate) fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            Self::U8(_) | Self::U32(_) | Self::I32(_) | Self::F32(_) | Self::Angle(_)
+        )
+    }
+
+    pub(crate) fn handle_event(&mut self, event: Event, constraints: &Constraints) {
+        let float_step = |m: Modifiers| constraints.step * f64::from(m.step());
+        let step = |m: Modifiers| constraints.integer_step() * f64::from(m.step());
+        let add = |value: f64, delta: f64| constraints.apply_integer(value + delta);
+        let add_float = |value: f64, delta: f64| constraints.apply(value + delta);
+
+        // Returns the signed step size for events which decrease or increase a number.
+        let number_delta = |event: Event, step: &dyn Fn(Modifiers) -> f64| match event {
+            Event::Down(m) | Event::Left(m) => Some(-step(m)),
+            Event::Up(m) | Event::Right(m) => Some(step(m)),
+            _ => None,
+        };
+
+        match self {
+            Self::U8(value) => {
+                if let Some(delta) = number_delta(event, &step) {
+                    **value = add(f64::from(**value), delta) as u8;
+                }
+            }
+            Self::U32(value) => {
+                if let Some(delta) = number_delta(event, &step) {
+                    **value = add(f64::from(**value), delta) as u32;
+                }
+            }
+            Self::I32(value) => {
+                if let Some(delta) = number_delta(event, &step) {
+                    **value = add(f64::from(**value), delta) as i32;
+                }
+            }
+            Self::F32(value) => {
+                if let Some(delta) = number_delta(event, &float_step) {
+                    **value = add_float(f64::from(**value), delta) as f32;
+                }
+            }
+            Self::Angle(angle) => {
+                if let Some(delta) = number_delta(event, &float_step) {
+                    let degrees = add_float(round_degrees(angle.to_degrees()), delta);
+                    **angle = Angle::from_degrees(degrees as f32);
+                }
+            }
+            Self::Point(point) => match event {
+                Event::Left(m) => point.x = add(f64::from(point.x), -step(m)) as i32,
+                Event::Right(m) => point.x = add(f64::from(point.x), step(m)) as i32,
+                Event::Up(m) => point.y = add(f64::from(point.y), -step(m)) as i32,
+                Event::Down(m) => point.y = add(f64::from(point.y), step(m)) as i32,
+                Event::MouseMove(p) => {
+                    point.x = add(f64::from(p.x), 0.0) as i32;
+                    point.y = add(f64::from(p.y), 0.0) as i32;
+                }
                 _ => {}
             },
-            Self::I32(value) => match event {
-                Event::Down | Event::Left => **value = value.saturating_sub(1),
-                Event::Up | Event::Right => **value = value.saturating_add(1),
+            Self::Size(size) => match event {
+                Event::Left(m) => size.width = add(f64::from(size.width), -step(m)) as u32,
+                Event::Right(m) => size.width = add(f64::from(size.width), step(m)) as u32,
+                Event::Up(m) => size.height = add(f64::from(size.height), -step(m)) as u32,
+                Event::Down(m) => size.height = add(f64::from(size.height), step(m)) as u32,
                 _ => {}
             },
-            Self::Point(point) => match event {
-                Event::Left => point.x = point.x.saturating_sub(1),
-                Event::Right => point.x = point.x.saturating_add(1),
-                Event::Up => point.y = point.y.saturating_sub(1),
-                Event::Down => point.y = point.y.saturating_add(1),
-                Event::MouseMove(p) => **point = p,
+            Self::Rectangle(rectangle) => match event {
+                // The arrow keys move the bottom right corner.
+                Event::Left(m) => {
+                    let size = &mut rectangle.size;
+                    size.width = add(f64::from(size.width), -step(m)) as u32;
+                }
+                Event::Right(m) => {
+                    let size = &mut rectangle.size;
+                    size.width = add(f64::from(size.width), step(m)) as u32;
+                }
+                Event::Up(m) => {
+                    let size = &mut rectangle.size;
+                    size.height = add(f64::from(size.height), -step(m)) as u32;
+                }
+                Event::Down(m) => {
+                    let size = &mut rectangle.size;
+                    size.height = add(f64::from(size.height), step(m)) as u32;
+                }
+                // Moves the corner which is closest to the mouse cursor. Dragging a corner past
+                // the opposite corner flips the rectangle.
+                Event::MouseMove(p) => {
+                    let top_left = rectangle.top_left;
+                    let bottom_right = rectangle.bottom_right().unwrap_or(top_left);
+
+                    let opposite =
+                        if distance_squared(p, top_left) < distance_squared(p, bottom_right) {
+                            bottom_right
+                        } else {
+                            top_left
+                        };
+
+                    let constrain = |point: Point| {
+                        Point::new(
+                            add(f64::from(point.x), 0.0) as i32,
+                            add(f64::from(point.y), 0.0) as i32,
+                        )
+                    };
+                    **rectangle = Rectangle::with_corners(constrain(p), constrain(opposite));
+                }
                 _ => {}
             },
             Self::Bool(value) => match event {
-                Event::Down | Event::Left | Event::Up | Event::Right => **value ^= true,
+                Event::Down(_) | Event::Left(_) | Event::Up(_) | Event::Right(_) => **value ^= true,
                 _ => {}
             },
+            Self::Choice(choice) => match event {
+                Event::Down(_) | Event::Left(_) => choice.previous(),
+                Event::Up(_) | Event::Right(_) => choice.next(),
+                _ => {}
+            },
+        }
+    }
+
+    /// Returns `true` if the value is inside the range.
+    ///
+    /// The range applies to both components of points and sizes and to the width and height of
+    /// rectangles. Choices are always inside the range.
+    fn is_in_range(&self, range: &RangeInclusive<f64>) -> bool {
+        match self {
+            Self::U8(value) => range.contains(&f64::from(**value)),
+            Self::U32(value) => range.contains(&f64::from(**value)),
+            Self::I32(value) => range.contains(&f64::from(**value)),
+            Self::F32(value) => range.contains(&f64::from(**value)),
+            Self::Angle(angle) => range.contains(&f64::from(angle.to_degrees())),
+            Self::Point(point) => {
+                range.contains(&f64::from(point.x)) && range.contains(&f64::from(point.y))
+            }
+            Self::Size(size) => {
+                range.contains(&f64::from(size.width)) && range.contains(&f64::from(size.height))
+            }
+            Self::Rectangle(rectangle) => {
+                range.contains(&f64::from(rectangle.size.width))
+                    && range.contains(&f64::from(rectangle.size.height))
+            }
+            Self::Bool(_) | Self::Choice(_) => true,
         }
     }
+
+    /// Sets the value by parsing a string.
+    ///
+    /// The accepted format is the same as the output of the `Display` impl, but the parentheses
+    /// around points and the degree sign after angles are optional. Rectangles can also be
+    /// entered as four comma separated numbers.
+    pub(crate) fn parse(&mut self, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        let invalid = || format!("invalid value \"{}\"", value);
+
+        match self {
+            Self::U8(v) => **v = value.parse().map_err(|_| invalid())?,
+            Self::U32(v) => **v = value.parse().map_err(|_| invalid())?,
+            Self::I32(v) => **v = value.parse().map_err(|_| invalid())?,
+            Self::F32(v) => **v = value.parse().map_err(|_| invalid())?,
+            Self::Angle(v) => {
+                let degrees = value.strip_suffix('°').unwrap_or(value);
+                **v = Angle::from_degrees(degrees.trim().parse().map_err(|_| invalid())?);
+            }
+            Self::Point(v) => {
+                let [x, y] = parse_numbers(value).ok_or_else(invalid)?;
+                **v = Point::new(x, y);
+            }
+            Self::Size(v) => {
+                let [width, height] = parse_numbers(value).ok_or_else(invalid)?;
+                **v = Size::new(width, height);
+            }
+            Self::Rectangle(v) => {
+                let [x, y, width, height] = parse_numbers(value).ok_or_else(invalid)?;
+                let size = Size::new(
+                    u32::try_from(width).map_err(|_| invalid())?,
+                    u32::try_from(height).map_err(|_| invalid())?,
+                );
+                **v = Rectangle::new(Point::new(x, y), size);
+            }
+            Self::Bool(v) => **v = value.parse().map_err(|_| invalid())?,
+            Self::Choice(choice) => {
+                let index = choice
+                    .names
+                    .iter()
+                    .position(|name| name == value)
+                    .ok_or_else(|| {
+                        format!(
+                            "invalid value \"{}\", expected one of {}",
+                            value,
+                            choice.names.join(", ")
+                        )
+                    })?;
+                choice.select(index);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a fixed number of numbers.
+///
+/// The numbers can be separated by commas, whitespace or `x` and parentheses are ignored.
+fn parse_numbers<T: FromStr, const N: usize>(value: &str) -> Option<[T; N]> {
+    let mut numbers = value
+        .split(|c: char| c == ',' || c == 'x' || c.is_whitespace())
+        .map(|number| number.trim_matches(|c| c == '(' || c == ')'))
+        .filter(|number| !number.is_empty());
+
+    let mut ret = Vec::with_capacity(N);
+    for number in numbers.by_ref().take(N) {
+        ret.push(number.parse().ok()?);
+    }
+
+    if numbers.next().is_some() {
+        return None;
+    }
+
+    ret.try_into().ok()
+}
+
+/// Returns the squared distance between two points.
+fn distance_squared(a: Point, b: Point) -> i64 {
+    let delta = a - b;
+
+    i64::from(delta.x).pow(2) + i64::from(delta.y).pow(2)
+}
+
+/// Formats an angle in degrees.
+///
+/// The value is rounded to 4 decimal places and trailing zeros are removed, which hides the
+/// rounding errors of the conversion from and to radians, e.g. `30°` instead of `30.000002°`.
+fn format_degrees(degrees: f32) -> String {
+    let text = format!("{:.4}", round_degrees(degrees));
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+
+    if text == "-0" {
+        "0".to_string()
+    } else {
+        text.to_string()
+    }
+}
+
+/// Rounds an angle in degrees to 4 decimal places.
+fn round_degrees(degrees: f32) -> f64 {
+    (f64::from(degrees) * 10_000.0).round() / 10_000.0
 }
 
 impl fmt::Display for Value<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            Value::U8(value) => value.fmt(f),
             Value::U32(value) => value.fmt(f),
             Value::I32(value) => value.fmt(f),
+            Value::F32(value) => value.fmt(f),
+            Value::Angle(angle) => write!(f, "{}°", format_degrees(angle.to_degrees())),
             Value::Point(Point { x, y }) => write!(f, "({}, {})", x, y),
+            Value::Size(Size { width, height }) => write!(f, "{}x{}", width, height),
+            Value::Rectangle(Rectangle { top_left, size }) => write!(
+                f,
+                "({}, {}) {}x{}",
+                top_left.x, top_left.y, size.width, size.height
+            ),
             Value::Bool(value) => value.fmt(f),
+            Value::Choice(choice) => choice.names[choice.selected].fmt(f),
         }
     }
 }
 
+impl<'a> From<&'a mut u8> for Value<'a> {
+    fn from(value: &'a mut u8) -> Self {
+        Self::U8(value)
+    }
+}
+
 impl<'a> From<&'a mut i32> for Value<'a> {
     fn from(value: &'a mut i32) -> Self {
         Self::I32(value)
@@ -81,14 +698,303 @@ impl<'a> From<&'a mut u32> for Value<'a> {
     }
 }
 
+impl<'a> From<&'a mut f32> for Value<'a> {
+    fn from(value: &'a mut f32) -> Self {
+        Self::F32(value)
+    }
+}
+
+impl<'a> From<&'a mut Angle> for Value<'a> {
+    fn from(value: &'a mut Angle) -> Self {
+        Self::Angle(value)
+    }
+}
+
 impl<'a> From<&'a mut Point> for Value<'a> {
     fn from(value: &'a mut Point) -> Self {
         Self::Point(value)
     }
 }
 
+impl<'a> From<&'a mut Size> for Value<'a> {
+    fn from(value: &'a mut Size) -> Self {
+        Self::Size(value)
+    }
+}
+
+impl<'a> From<&'a mut Rectangle> for Value<'a> {
+    fn from(value: &'a mut Rectangle) -> Self {
+        Self::Rectangle(value)
+    }
+}
+
 impl<'a> From<&'a mut bool> for Value<'a> {
     fn from(value: &'a mut bool) -> Self {
         Self::Bool(value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn right(mut parameter: Parameter) {
+        parameter.handle_event(Event::Right(Modifiers::default()));
+    }
+
+    fn left(mut parameter: Parameter) {
+        parameter.handle_event(Event::Left(Modifiers::default()));
+    }
+
+    #[test]
+    fn parse_point() {
+        let mut point = Point::zero();
+
+        for text in ["(40, 60)", "40,60", " 40 60 ", "(40,60)"] {
+            Parameter::new("center", &mut point).parse(text).unwrap();
+            assert_eq!(point, Point::new(40, 60), "{}", text);
+        }
+
+        Parameter::new("center", &mut point)
+            .parse("-4,120")
+            .unwrap();
+        assert_eq!(point, Point::new(-4, 120));
+
+        for text in ["40", "40,60,80", "(40, x)", ""] {
+            assert!(Parameter::new("center", &mut point).parse(text).is_err());
+        }
+        assert_eq!(point, Point::new(-4, 120));
+    }
+
+    #[test]
+    fn parse_size_and_rectangle() {
+        let mut size = Size::zero();
+        Parameter::new("size", &mut size).parse("20x30").unwrap();
+        assert_eq!(size, Size::new(20, 30));
+
+        let mut rectangle = Rectangle::zero();
+        let expected = Rectangle::new(Point::new(-5, 10), Size::new(20, 30));
+        for text in ["(-5, 10) 20x30", "-5,10,20,30"] {
+            Parameter::new("rectangle", &mut rectangle)
+                .parse(text)
+                .unwrap();
+            assert_eq!(rectangle, expected, "{}", text);
+        }
+
+        assert!(Parameter::new("rectangle", &mut rectangle)
+            .parse("(0, 0) -1x5")
+            .is_err());
+    }
+
+    #[test]
+    fn parse_restores_exact_value() {
+        let mut angle = Angle::from_degrees(12.345678);
+        let previous = angle;
+
+        assert!(Parameter::new("start", &mut angle)
+            .range(0..=90)
+            .parse("120")
+            .is_err());
+        assert_eq!(angle, previous);
+
+        let mut value = 0.123456f32;
+        assert!(Parameter::new("value", &mut value)
+            .range(0..=1)
+            .parse("2")
+            .is_err());
+        assert_eq!(value, 0.123456);
+    }
+
+    #[test]
+    fn parse_outside_of_range() {
+        let mut point = Point::new(1, 2);
+
+        assert!(Parameter::new("center", &mut point)
+            .range(0..=50)
+            .parse("40, 60")
+            .is_err());
+        assert_eq!(point, Point::new(1, 2));
+    }
+
+    #[test]
+    fn wrap_keeps_remainder() {
+        fn start(angle: &mut Angle) -> Parameter<'_> {
+            Parameter::new("start", angle)
+                .range(0..=360)
+                .step(10)
+                .wrap()
+        }
+
+        let mut angle = Angle::from_degrees(355.0);
+        right(start(&mut angle));
+        assert_eq!(angle.to_degrees().round(), 5.0);
+
+        left(start(&mut angle));
+        assert_eq!(angle.to_degrees().round(), 355.0);
+    }
+
+    #[test]
+    fn integer_wrap() {
+        fn value(value: &mut u32) -> Parameter<'_> {
+            Parameter::new("value", value).range(0..=5).wrap()
+        }
+
+        let mut v = 5;
+        right(value(&mut v));
+        assert_eq!(v, 0);
+
+        left(value(&mut v));
+        assert_eq!(v, 5);
+
+        let mut v = 4;
+        right(value(&mut v).step(3));
+        assert_eq!(v, 1);
+
+        let mut v = -3;
+        left(Parameter::new("value", &mut v).range(-3..=3).wrap());
+        assert_eq!(v, 3);
+    }
+
+    #[test]
+    fn valid_values() {
+        let mut value = 0u8;
+        let values = Parameter::new("value", &mut value)
+            .range(250..=300)
+            .valid_values();
+        assert_eq!(values.unwrap(), ["250", "251", "252", "253", "254", "255"]);
+
+        let mut value = 0u32;
+        let values = Parameter::new("value", &mut value)
+            .range(-2..=6)
+            .step(3)
+            .valid_values();
+        assert_eq!(values.unwrap(), ["0", "3", "6"]);
+
+        let mut value = 0i32;
+        assert!(Parameter::new("value", &mut value)
+            .range(i32::MIN..=i32::MAX)
+            .valid_values()
+            .is_none());
+        assert!(Parameter::new("value", &mut value).valid_values().is_none());
+
+        let mut value = 0.0f32;
+        assert!(Parameter::new("value", &mut value)
+            .range(0.0..=1.0)
+            .step(1e-9)
+            .valid_values()
+            .is_none());
+    }
+
+    #[test]
+    fn clamp_without_wrap() {
+        let mut value = 8u32;
+        right(Parameter::new("value", &mut value).range(0..=10).step(5));
+        assert_eq!(value, 10);
+
+        left(Parameter::new("value", &mut value).range(8..=10).step(5));
+        assert_eq!(value, 8);
+    }
+
+    #[test]
+    fn fractional_integer_step() {
+        let mut value = 3u32;
+        right(Parameter::new("value", &mut value).step(0.4));
+        assert_eq!(value, 4);
+
+        let mut value = 3i32;
+        left(Parameter::new("value", &mut value).step(2.6));
+        assert_eq!(value, 0);
+    }
+
+    #[test]
+    fn angle_display() {
+        for (degrees, expected) in [(30.0, "30°"), (-12.5, "-12.5°"), (0.123456, "0.1235°")] {
+            let mut angle = Angle::from_radians(Angle::from_degrees(degrees).to_radians());
+            assert_eq!(Value::from(&mut angle).to_string(), expected);
+        }
+
+        let mut angle = Angle::from_degrees(-0.00001);
+        assert_eq!(Value::from(&mut angle).to_string(), "0°");
+    }
+
+    #[test]
+    fn drag_rectangle_corner() {
+        let mut rectangle = Rectangle::new(Point::new(10, 10), Size::new(11, 11));
+
+        // Dragging the top left corner past the right edge flips the rectangle.
+        Parameter::new("rectangle", &mut rectangle)
+            .handle_event(Event::MouseMove(Point::new(24, 4)));
+
+        assert_eq!(
+            rectangle,
+            Rectangle::new(Point::new(20, 4), Size::new(5, 17))
+        );
+    }
+
+    #[test]
+    fn choice_outside_of_options() {
+        let options = [
+            ("Center", StrokeAlignment::Center),
+            ("Inside", StrokeAlignment::Inside),
+        ];
+
+        let mut alignment = StrokeAlignment::Outside;
+        let parameter = Parameter::choice("alignment", &mut alignment, &options);
+        assert_eq!(parameter.value.to_string(), "Center");
+        drop(parameter);
+        assert_eq!(alignment, StrokeAlignment::Center);
+
+        Parameter::choice("alignment", &mut alignment, &options)
+            .parse("Inside")
+            .unwrap();
+        assert_eq!(alignment, StrokeAlignment::Inside);
+    }
+
+    #[test]
+    fn choice_index() {
+        let mut index = 5;
+        let parameter = Parameter::choice_index("font", &mut index, &["A", "B", "C"]);
+        assert_eq!(parameter.value.to_string(), "A");
+        drop(parameter);
+        assert_eq!(index, 0);
+
+        let parameter = Parameter::choice_index("font", &mut index, &["A", "B", "C"]);
+
+        left(parameter);
+        assert_eq!(index, 2);
+
+        Parameter::choice_index("font", &mut index, &["A", "B", "C"])
+            .parse("B")
+            .unwrap();
+        assert_eq!(index, 1);
+    }
+
+    #[test]
+    fn drag_rectangle_corner_in_range() {
+        let mut rectangle = Rectangle::new(Point::new(10, 10), Size::new(11, 11));
+
+        Parameter::new("rectangle", &mut rectangle)
+            .range(0..=50)
+            .handle_event(Event::MouseMove(Point::new(70, -5)));
+
+        assert_eq!(
+            rectangle,
+            Rectangle::with_corners(Point::new(10, 10), Point::new(50, 0))
+        );
+    }
+
+    #[test]
+    fn rectangle_arrow_keys() {
+        let mut rectangle = Rectangle::new(Point::new(10, 10), Size::new(3, 5));
+
+        left(Parameter::new("rectangle", &mut rectangle).step(5));
+        assert_eq!(rectangle.size, Size::new(0, 5));
+
+        right(
+            Parameter::new("rectangle", &mut rectangle)
+                .range(1..=4)
+                .step(5),
+        );
+        assert_eq!(rectangle.size, Size::new(4, 5));
+    }
+}
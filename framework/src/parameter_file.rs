@@ -0,0 +1,180 @@
+//! Parameter files.
+//!
+//! Parameter files store the values of all parameters in a human readable format, with one
+//! `name = value` pair per line:
+//!
+//! ```text
+//! center = (40, 60)
+//! diameter = 17
+//! show BB = true
+//! ```
+//!
+//! Empty lines and lines starting with `#` are ignored.
+
+use std::{fmt::Write, fs, path::Path};
+
+use crate::Parameter;
+
+/// Writes the values of all parameters to a file.
+pub(crate) fn save<P: AsRef<Path>>(parameters: &[Parameter], path: P) -> Result<(), String> {
+    let path = path.as_ref();
+
+    fs::write(path, to_string(parameters))
+        .map_err(|e| format!("couldn't write \"{}\": {}", path.display(), e))
+}
+
+/// Sets the parameters to the values stored in a file.
+pub(crate) fn load<P: AsRef<Path>>(parameters: &mut [Parameter], path: P) -> Result<(), String> {
+    let path = path.as_ref();
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("couldn't read \"{}\": {}", path.display(), e))?;
+
+    apply(parameters, &content).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Converts the parameter values into the file format.
+pub(crate) fn to_string(parameters: &[Parameter]) -> String {
+    let mut output = String::new();
+
+    for parameter in parameters {
+        writeln!(output, "{} = {}", parameter.name, parameter.value).unwrap();
+    }
+
+    output
+}
+
+/// Sets the parameters to the values in a string in the file format.
+pub(crate) fn apply(parameters: &mut [Parameter], content: &str) -> Result<(), String> {
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        set(parameters, line).map_err(|e| format!("line {}: {}", index + 1, e))?;
+    }
+
+    Ok(())
+}
+
+/// Sets a single parameter using a `name = value` assignment.
+pub(crate) fn set(parameters: &mut [Parameter], assignment: &str) -> Result<(), String> {
+    let (name, value) = assignment
+        .split_once('=')
+        .ok_or_else(|| format!("expected \"name = value\", got \"{}\"", assignment))?;
+    let name = name.trim();
+
+    let parameter = parameters
+        .iter_mut()
+        .find(|parameter| parameter.name == name)
+        .ok_or_else(|| format!("unknown parameter \"{}\"", name))?;
+
+    parameter
+        .parse(value)
+        .map_err(|e| format!("{} for parameter \"{}\"", e, name))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use embedded_graphics::{prelude::*, primitives::Rectangle};
+
+    use super::*;
+
+    struct Values {
+        center: Point,
+        diameter: u32,
+        start: Angle,
+        rectangle: Rectangle,
+        show_bounding_box: bool,
+    }
+
+    impl Values {
+        fn zero() -> Self {
+            Self {
+                center: Point::zero(),
+                diameter: 0,
+                start: Angle::zero(),
+                rectangle: Rectangle::zero(),
+                show_bounding_box: false,
+            }
+        }
+
+        fn parameters(&mut self) -> Vec<Parameter> {
+            vec![
+                Parameter::new("center", &mut self.center),
+                Parameter::new("diameter", &mut self.diameter),
+                Parameter::new("start", &mut self.start),
+                Parameter::new("rectangle", &mut self.rectangle),
+                Parameter::new("show BB", &mut self.show_bounding_box),
+            ]
+        }
+    }
+
+    fn values() -> Values {
+        Values {
+            center: Point::new(40, -60),
+            diameter: 17,
+            start: Angle::from_degrees(30.0),
+            rectangle: Rectangle::new(Point::new(-5, 10), Size::new(20, 30)),
+            show_bounding_box: true,
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut expected = values();
+        let content = to_string(&expected.parameters());
+
+        let mut actual = Values::zero();
+        apply(&mut actual.parameters(), &content).unwrap();
+
+        assert_eq!(actual.center, expected.center);
+        assert_eq!(actual.diameter, expected.diameter);
+        assert_eq!(actual.start.to_degrees().round(), 30.0);
+        assert_eq!(actual.rectangle, expected.rectangle);
+        assert_eq!(actual.show_bounding_box, expected.show_bounding_box);
+        assert_eq!(to_string(&actual.parameters()), content);
+    }
+
+    #[test]
+    fn save_and_load() {
+        let path = env::temp_dir().join(format!("framework-{}.parameters", std::process::id()));
+
+        let mut expected = values();
+        save(&expected.parameters(), &path).unwrap();
+
+        let mut actual = Values::zero();
+        let result = load(&mut actual.parameters(), &path);
+        fs::remove_file(&path).unwrap();
+        result.unwrap();
+
+        assert_eq!(
+            to_string(&actual.parameters()),
+            to_string(&expected.parameters())
+        );
+    }
+
+    #[test]
+    fn comments_and_errors() {
+        let mut values = Values::zero();
+
+        apply(
+            &mut values.parameters(),
+            "# comment\n\n  diameter = 5  \nshow BB=true",
+        )
+        .unwrap();
+        assert_eq!(values.diameter, 5);
+        assert!(values.show_bounding_box);
+
+        assert_eq!(
+            apply(&mut values.parameters(), "diameter = 6\nradius = 3"),
+            Err("line 2: unknown parameter \"radius\"".to_string())
+        );
+        assert_eq!(values.diameter, 6);
+
+        assert!(apply(&mut values.parameters(), "center").is_err());
+    }
+}
@@ -0,0 +1,143 @@
+use embedded_graphics::{
+    geometry::AnchorPoint,
+    mono_font::{iso_8859_1::FONT_6X10, MonoTextStyle},
+    prelude::*,
+    primitives::PrimitiveStyleBuilder,
+    text::{Baseline, Text},
+};
+use embedded_graphics_simulator::{sdl2::Keycode, SimulatorEvent};
+
+/// App picker used by the launcher.
+pub(crate) struct Picker {
+    names: Vec<&'static str>,
+    current: usize,
+    selected: usize,
+    open: bool,
+}
+
+impl Picker {
+    pub(crate) fn new(names: Vec<&'static str>) -> Self {
+        Self {
+            names,
+            current: 0,
+            selected: 0,
+            open: false,
+        }
+    }
+
+    pub(crate) fn set_open(&mut self, open: bool) {
+        self.open = open;
+    }
+
+    /// Sets the index of the currently running app.
+    pub(crate) fn set_current(&mut self, current: usize) {
+        self.current = current;
+        self.selected = current;
+    }
+
+    pub(crate) fn draw<T>(
+        &self,
+        target: &mut T,
+        color: T::Color,
+        background_color: T::Color,
+    ) -> Result<(), T::Error>
+    where
+        T: DrawTarget,
+    {
+        if !self.open {
+            return Ok(());
+        }
+
+        let max_name_width = self.names.iter().map(|name| name.len()).max().unwrap_or(0);
+
+        let size = Size::new(
+            (max_name_width as u32 + 2) * 6 + 8,
+            self.names.len() as u32 * 10 + 8,
+        );
+        let rect = target.bounding_box().resized(size, AnchorPoint::Center);
+
+        rect.into_styled(
+            PrimitiveStyleBuilder::new()
+                .fill_color(background_color)
+                .stroke_color(color)
+                .stroke_width(1)
+                .build(),
+        )
+        .draw(target)?;
+
+        let style = MonoTextStyle::new(&FONT_6X10, color);
+        let mut position = rect.top_left + Point::new(4, 4);
+
+        for (index, name) in self.names.iter().enumerate() {
+            if index == self.selected {
+                Text::with_baseline(">", position, style, Baseline::Top).draw(target)?;
+            }
+
+            Text::with_baseline(name, position + Point::new(12, 0), style, Baseline::Top)
+                .draw(target)?;
+
+            position.y += 10;
+        }
+
+        Ok(())
+    }
+
+    /// Handles an event.
+    ///
+    /// `Tab` toggles the picker and all events are consumed while it is open.
+    pub(crate) fn handle_event(&mut self, event: &SimulatorEvent) -> PickerEvent {
+        if let SimulatorEvent::KeyDown {
+            keycode: Keycode::Tab,
+            ..
+        } = event
+        {
+            self.open ^= true;
+            self.selected = self.current;
+
+            return PickerEvent::Handled;
+        }
+
+        if !self.open {
+            return PickerEvent::Ignored;
+        }
+
+        if let SimulatorEvent::KeyDown { keycode, .. } = event {
+            match *keycode {
+                Keycode::Up => {
+                    if self.selected > 0 {
+                        self.selected -= 1;
+                    } else {
+                        self.selected = self.names.len() - 1;
+                    }
+                }
+                Keycode::Down => {
+                    self.selected += 1;
+                    if self.selected >= self.names.len() {
+                        self.selected = 0;
+                    }
+                }
+                Keycode::Return | Keycode::Space => {
+                    self.open = false;
+
+                    if self.selected != self.current {
+                        return PickerEvent::Selected(self.selected);
+                    }
+                }
+                Keycode::Escape => self.open = false,
+                _ => {}
+            }
+        }
+
+        PickerEvent::Handled
+    }
+}
+
+/// Result of [`Picker::handle_event`].
+pub(crate) enum PickerEvent {
+    /// The event wasn't used by the picker.
+    Ignored,
+    /// The event was used by the picker.
+    Handled,
+    /// An app was selected.
+    Selected(usize),
+}
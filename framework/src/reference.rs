@@ -0,0 +1,389 @@
+//! Reference rasterizer.
+//!
+//! The reference rasterizer is a slow, floating point based implementation of the e-g primitives,
+//! which decides for each pixel whether its center is inside the exact shape. Its output can be
+//! used as an objective metric for the accuracy of the e-g rasterizers, e.g. by returning it from
+//! [`App::draw_reference`](crate::App::draw_reference) and using the compare mode, or by using
+//! [`PixelDiff::from_reference`](crate::compare::PixelDiff::from_reference). Both compare the
+//! pixel colors, so a misplaced boundary between the stroke and the fill is reported as differing
+//! pixels even if e-g and the reference cover the same area.
+//!
+//! The stroke and fill areas are determined in the same way as in e-g. The shape is offset by
+//! the outside stroke width to get the outer edge of the stroke and by the inside stroke width to
+//! get the inner edge. See the [`geometry`](crate::geometry) module for a description of the
+//! coordinate system.
+
+use std::f32::consts::TAU;
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{
+        Arc, Circle, Ellipse, Line, PrimitiveStyle, Rectangle, RoundedRectangle, Sector, Styled,
+    },
+};
+
+use crate::geometry::{ellipse_in, stroke_widths};
+
+/// Drawable that can be drawn by the reference rasterizer.
+pub trait ReferenceDrawable {
+    /// Color type.
+    type Color: PixelColor;
+
+    /// Draws the drawable by using the reference rasterizer.
+    fn draw_reference<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>;
+}
+
+/// Returns the center of a pixel in continuous coordinates.
+fn pixel_center(point: Point) -> (f32, f32) {
+    (point.x as f32 + 0.5, point.y as f32 + 0.5)
+}
+
+/// Returns a rectangle which is expanded by `offset` on each side.
+fn expand(rectangle: &Rectangle, offset: u32) -> Rectangle {
+    Rectangle::new(
+        rectangle.top_left - Point::new_equal(offset as i32),
+        rectangle.size + Size::new_equal(offset * 2),
+    )
+}
+
+/// Draws a closed shape.
+///
+/// `contains` must return `true` if a point is inside the shape after it was offset by the given
+/// distance. Positive offsets grow the shape and negative offsets shrink it. Only points inside
+/// `area` are tested.
+fn draw_closed_shape<C, D, F>(
+    area: &Rectangle,
+    style: &PrimitiveStyle<C>,
+    contains: F,
+    target: &mut D,
+) -> Result<(), D::Error>
+where
+    C: PixelColor,
+    D: DrawTarget<Color = C>,
+    F: Fn((f32, f32), f32) -> bool,
+{
+    let (outside, inside) = stroke_widths(style);
+    let stroke_color = style.stroke_color.filter(|_| outside + inside > 0.0);
+
+    let area = expand(area, outside.ceil() as u32 + 1);
+
+    target.draw_iter(area.points().filter_map(|p| {
+        let center = pixel_center(p);
+        let in_fill_area = contains(center, -inside);
+
+        let color = if !in_fill_area && contains(center, outside) {
+            stroke_color
+        } else if in_fill_area {
+            style.fill_color
+        } else {
+            None
+        };
+
+        color.map(|color| Pixel(p, color))
+    }))
+}
+
+/// Draws the stroke of an open shape.
+///
+/// `in_stroke` must return `true` if a point is inside the stroke. Only points inside `area` are
+/// tested.
+fn draw_open_shape<C, D, F>(
+    area: &Rectangle,
+    style: &PrimitiveStyle<C>,
+    in_stroke: F,
+    target: &mut D,
+) -> Result<(), D::Error>
+where
+    C: PixelColor,
+    D: DrawTarget<Color = C>,
+    F: Fn((f32, f32)) -> bool,
+{
+    let stroke_color = match style.stroke_color {
+        Some(color) if style.stroke_width > 0 => color,
+        _ => return Ok(()),
+    };
+
+    let area = expand(area, style.stroke_width + 1);
+
+    target.draw_iter(
+        area.points()
+            .filter(|p| in_stroke(pixel_center(*p)))
+            .map(|p| Pixel(p, stroke_color)),
+    )
+}
+
+/// Returns `true` if a point is inside an ellipse.
+fn ellipse_contains(point: (f32, f32), center: (f32, f32), radii: (f32, f32)) -> bool {
+    if radii.0 <= 0.0 || radii.1 <= 0.0 {
+        return false;
+    }
+
+    let x = (point.0 - center.0) / radii.0;
+    let y = (point.1 - center.1) / radii.1;
+
+    x * x + y * y <= 1.0
+}
+
+/// Returns `true` if an angle is inside the angular range of an arc.
+///
+/// All angles are in radians.
+fn angle_in_range(angle: f32, start: f32, sweep: f32) -> bool {
+    if sweep.abs() >= TAU {
+        return true;
+    }
+
+    let (start, sweep) = if sweep < 0.0 {
+        (start + sweep, -sweep)
+    } else {
+        (start, sweep)
+    };
+
+    (angle - start).rem_euclid(TAU) <= sweep
+}
+
+/// Returns the distance between a point and a line segment.
+fn segment_distance(point: (f32, f32), start: (f32, f32), end: (f32, f32)) -> f32 {
+    let delta = (end.0 - start.0, end.1 - start.1);
+    let length_squared = delta.0 * delta.0 + delta.1 * delta.1;
+
+    let t = if length_squared > 0.0 {
+        (((point.0 - start.0) * delta.0 + (point.1 - start.1) * delta.1) / length_squared)
+            .clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    let closest = (start.0 + delta.0 * t, start.1 + delta.1 * t);
+
+    (point.0 - closest.0).hypot(point.1 - closest.1)
+}
+
+/// Returns the signed distance between a point and the outline of a sector.
+///
+/// The distance is negative for points inside the sector.
+fn sector_distance(
+    point: (f32, f32),
+    center: (f32, f32),
+    radius: f32,
+    start: f32,
+    sweep: f32,
+) -> f32 {
+    let delta = (point.0 - center.0, point.1 - center.1);
+    let distance = delta.0.hypot(delta.1);
+
+    if sweep.abs() >= TAU {
+        return distance - radius;
+    }
+
+    let in_angle = angle_in_range(delta.1.atan2(delta.0), start, sweep);
+
+    let radial_distance = |angle: f32| {
+        let end = (
+            center.0 + radius * angle.cos(),
+            center.1 + radius * angle.sin(),
+        );
+
+        segment_distance(point, center, end)
+    };
+
+    let mut outline_distance = radial_distance(start).min(radial_distance(start + sweep));
+    if in_angle {
+        outline_distance = outline_distance.min((distance - radius).abs());
+    }
+
+    if in_angle && distance <= radius {
+        -outline_distance
+    } else {
+        outline_distance
+    }
+}
+
+/// Returns `true` if a point is inside a rounded rectangle with elliptical corners.
+///
+/// The rectangle and the corner radii are offset by `offset`.
+fn rounded_rectangle_contains(
+    point: (f32, f32),
+    rounded_rectangle: &RoundedRectangle,
+    offset: f32,
+) -> bool {
+    let rectangle = &rounded_rectangle.rectangle;
+    let corners = &rounded_rectangle.corners;
+
+    let left = rectangle.top_left.x as f32 - offset;
+    let top = rectangle.top_left.y as f32 - offset;
+    let right = left + rectangle.size.width as f32 + offset * 2.0;
+    let bottom = top + rectangle.size.height as f32 + offset * 2.0;
+
+    if point.0 < left || point.0 > right || point.1 < top || point.1 > bottom {
+        return false;
+    }
+
+    let radii = |size: Size| {
+        (
+            (size.width as f32 + offset).max(0.0),
+            (size.height as f32 + offset).max(0.0),
+        )
+    };
+
+    let (x, y) = point;
+    let corners = [
+        (radii(corners.top_left), (left, top), (1.0, 1.0)),
+        (radii(corners.top_right), (right, top), (-1.0, 1.0)),
+        (radii(corners.bottom_right), (right, bottom), (-1.0, -1.0)),
+        (radii(corners.bottom_left), (left, bottom), (1.0, -1.0)),
+    ];
+
+    corners.iter().all(|&(radii, corner, direction)| {
+        let center = (
+            corner.0 + radii.0 * direction.0,
+            corner.1 + radii.1 * direction.1,
+        );
+        let in_corner = (x - center.0) * direction.0 < 0.0 && (y - center.1) * direction.1 < 0.0;
+
+        !in_corner || ellipse_contains(point, center, radii)
+    })
+}
+
+impl<C: PixelColor> ReferenceDrawable for Styled<Circle, PrimitiveStyle<C>> {
+    type Color = C;
+
+    fn draw_reference<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let bounding_box = self.primitive.bounding_box();
+        let (center, radii) = ellipse_in(&bounding_box);
+
+        draw_closed_shape(
+            &bounding_box,
+            &self.style,
+            |p, offset| ellipse_contains(p, center, (radii.0 + offset, radii.1 + offset)),
+            target,
+        )
+    }
+}
+
+impl<C: PixelColor> ReferenceDrawable for Styled<Ellipse, PrimitiveStyle<C>> {
+    type Color = C;
+
+    fn draw_reference<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let bounding_box = self.primitive.bounding_box();
+        let (center, radii) = ellipse_in(&bounding_box);
+
+        draw_closed_shape(
+            &bounding_box,
+            &self.style,
+            |p, offset| ellipse_contains(p, center, (radii.0 + offset, radii.1 + offset)),
+            target,
+        )
+    }
+}
+
+impl<C: PixelColor> ReferenceDrawable for Styled<Arc, PrimitiveStyle<C>> {
+    type Color = C;
+
+    fn draw_reference<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let bounding_box = self.primitive.to_circle().bounding_box();
+        let (center, radii) = ellipse_in(&bounding_box);
+        let (outside, inside) = stroke_widths(&self.style);
+
+        let start = self.primitive.angle_start.to_radians();
+        let sweep = self.primitive.angle_sweep.to_radians();
+
+        draw_open_shape(
+            &bounding_box,
+            &self.style,
+            |p| {
+                let delta = (p.0 - center.0, p.1 - center.1);
+                let distance = delta.0.hypot(delta.1);
+
+                distance <= radii.0 + outside
+                    && distance >= radii.0 - inside
+                    && angle_in_range(delta.1.atan2(delta.0), start, sweep)
+            },
+            target,
+        )
+    }
+}
+
+impl<C: PixelColor> ReferenceDrawable for Styled<Sector, PrimitiveStyle<C>> {
+    type Color = C;
+
+    fn draw_reference<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let bounding_box = self.primitive.to_circle().bounding_box();
+        let (center, radii) = ellipse_in(&bounding_box);
+
+        let start = self.primitive.angle_start.to_radians();
+        let sweep = self.primitive.angle_sweep.to_radians();
+
+        draw_closed_shape(
+            &bounding_box,
+            &self.style,
+            |p, offset| sector_distance(p, center, radii.0, start, sweep) <= offset,
+            target,
+        )
+    }
+}
+
+impl<C: PixelColor> ReferenceDrawable for Styled<RoundedRectangle, PrimitiveStyle<C>> {
+    type Color = C;
+
+    fn draw_reference<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let rounded_rectangle = self.primitive.confine_radii();
+
+        draw_closed_shape(
+            &rounded_rectangle.rectangle,
+            &self.style,
+            |p, offset| rounded_rectangle_contains(p, &rounded_rectangle, offset),
+            target,
+        )
+    }
+}
+
+impl<C: PixelColor> ReferenceDrawable for Styled<Line, PrimitiveStyle<C>> {
+    type Color = C;
+
+    fn draw_reference<D>(&self, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = Self::Color>,
+    {
+        let line = &self.primitive;
+
+        let start = pixel_center(line.start);
+        let end = pixel_center(line.end);
+        let delta = (end.0 - start.0, end.1 - start.1);
+        let length = delta.0.hypot(delta.1);
+        let half_width = self.style.stroke_width as f32 / 2.0;
+
+        if length == 0.0 {
+            return Ok(());
+        }
+
+        draw_open_shape(
+            &Rectangle::with_corners(line.start, line.end),
+            &self.style,
+            |p| {
+                let relative = (p.0 - start.0, p.1 - start.1);
+                let along = (relative.0 * delta.0 + relative.1 * delta.1) / length;
+                let across = (relative.0 * delta.1 - relative.1 * delta.0) / length;
+
+                (0.0..=length).contains(&along) && across.abs() <= half_width
+            },
+            target,
+        )
+    }
+}
@@ -0,0 +1,50 @@
+use embedded_graphics::{
+    geometry::AnchorPoint,
+    mono_font::{iso_8859_1::FONT_6X10, MonoTextStyle},
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+    text::{Baseline, Text},
+};
+
+/// Draws the status panel at the bottom of the display.
+///
+/// Each entry in `status` can contain multiple lines. Nothing is drawn if `status` is empty.
+pub(crate) fn draw_status_panel<T>(
+    status: &[String],
+    target: &mut T,
+    color: T::Color,
+    background_color: T::Color,
+) -> Result<(), T::Error>
+where
+    T: DrawTarget,
+{
+    if status.is_empty() {
+        return Ok(());
+    }
+
+    let text = status.join("\n");
+    let lines = text.lines().count() as u32;
+
+    let bounding_box = target.bounding_box();
+    let panel = Rectangle::new(
+        Point::zero(),
+        Size::new(bounding_box.size.width, lines * 10 + 2),
+    )
+    .translate(
+        bounding_box.anchor_point(AnchorPoint::BottomLeft) - Point::new(0, lines as i32 * 10 + 1),
+    );
+
+    panel
+        .into_styled(PrimitiveStyle::with_fill(background_color))
+        .draw(target)?;
+
+    Text::with_baseline(
+        &text,
+        panel.top_left + Point::new(2, 1),
+        MonoTextStyle::new(&FONT_6X10, color),
+        Baseline::Top,
+    )
+    .draw(target)?;
+
+    Ok(())
+}
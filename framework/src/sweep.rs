@@ -0,0 +1,213 @@
+//! Parameter sweeps.
+//!
+//! A sweep renders one frame for each value in a range of parameter values and writes the frames
+//! to an animated GIF. This makes it easy to show rasterization discontinuities, e.g. by sweeping
+//! the diameter of a circle:
+//!
+//! ```text
+//! cargo run -- circle --headless circle.gif --scale 3 --sweep diameter=10..=60
+//! ```
+
+use std::{fmt, fs::File, io::BufWriter, path::Path, str::FromStr, time::Duration};
+
+use image::{
+    codecs::gif::{GifEncoder, Repeat},
+    imageops::{self, FilterType},
+    Delay, DynamicImage, Frame,
+};
+
+use crate::{testing::to_image, App, AppExt};
+
+/// Sweep of a numeric parameter over a range of values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sweep {
+    /// Parameter name.
+    pub parameter: String,
+    /// First value.
+    pub start: i32,
+    /// Last value.
+    ///
+    /// The last value is included in the sweep, unless it isn't a multiple of the step size
+    /// away from the start value.
+    pub end: i32,
+    /// Step size.
+    pub step: u32,
+    /// Duration of a single frame.
+    pub frame_delay: Duration,
+}
+
+impl Sweep {
+    /// Creates a new sweep with a step size of `1`.
+    pub fn new(parameter: &str, start: i32, end: i32) -> Self {
+        Self {
+            parameter: parameter.to_string(),
+            start,
+            end,
+            step: 1,
+            frame_delay: Duration::from_millis(100),
+        }
+    }
+
+    /// Returns the parameter values.
+    ///
+    /// The values are decreasing if the end value is less than the start value.
+    pub fn values(&self) -> impl Iterator<Item = i32> {
+        let (start, end) = (i64::from(self.start), i64::from(self.end));
+        let step = i64::from(self.step.max(1));
+        let count = (end - start).abs() / step + 1;
+        let step = if end < start { -step } else { step };
+
+        (0..count).map(move |i| (start + i * step) as i32)
+    }
+}
+
+impl FromStr for Sweep {
+    type Err = String;
+
+    /// Parses a sweep in the `name=start..=end` or `name=start..end` format.
+    ///
+    /// The end value isn't included if the exclusive `..` range syntax is used.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (parameter, range) = s
+            .split_once('=')
+            .ok_or_else(|| format!("expected NAME=START..END, got \"{}\"", s))?;
+
+        let (start, end) = range
+            .split_once("..")
+            .ok_or_else(|| format!("expected a range like 0..=10, got \"{}\"", range))?;
+
+        let parse = |value: &str| {
+            value
+                .trim()
+                .parse::<i32>()
+                .map_err(|_| format!("invalid sweep value \"{}\"", value.trim()))
+        };
+
+        let start = parse(start)?;
+        let end = match end.strip_prefix('=') {
+            Some(end) => parse(end)?,
+            None => {
+                let end = parse(end)?;
+                if end == start {
+                    return Err(format!("empty sweep range \"{}\"", range));
+                }
+
+                if end > start {
+                    end - 1
+                } else {
+                    end + 1
+                }
+            }
+        };
+
+        Ok(Self::new(parameter.trim(), start, end))
+    }
+}
+
+impl fmt::Display for Sweep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}..={}", self.parameter, self.start, self.end)
+    }
+}
+
+/// Renders a sweep to an animated GIF.
+pub(crate) fn render_to_gif<T: App>(
+    app: &mut T,
+    path: &Path,
+    sweep: &Sweep,
+    scale: u32,
+    show_menu: bool,
+) -> Result<(), String> {
+    let mut frames = Vec::new();
+
+    for value in sweep.values() {
+        app.set_parameter(&format!("{}={}", sweep.parameter, value))?;
+
+        let image = to_image(&app.render(show_menu));
+        let image = imageops::resize(
+            &image,
+            image.width() * scale,
+            image.height() * scale,
+            FilterType::Nearest,
+        );
+
+        frames.push(Frame::from_parts(
+            DynamicImage::ImageRgb8(image).into_rgba8(),
+            0,
+            0,
+            Delay::from_saturating_duration(sweep.frame_delay),
+        ));
+    }
+
+    let write_error =
+        |e: &dyn fmt::Display| format!("couldn't write \"{}\": {}", path.display(), e);
+
+    let file = File::create(path).map_err(|e| write_error(&e))?;
+    let mut encoder = GifEncoder::new(BufWriter::new(file));
+    encoder
+        .set_repeat(Repeat::Infinite)
+        .map_err(|e| write_error(&e))?;
+    encoder.encode_frames(frames).map_err(|e| write_error(&e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values(sweep: &str) -> Vec<i32> {
+        sweep.parse::<Sweep>().unwrap().values().collect()
+    }
+
+    #[test]
+    fn inclusive() {
+        assert_eq!(values("diameter=3..=6"), [3, 4, 5, 6]);
+        assert_eq!(values("diameter = 4 ..= 4"), [4]);
+    }
+
+    #[test]
+    fn exclusive() {
+        assert_eq!(values("diameter=3..6"), [3, 4, 5]);
+        assert_eq!(values("diameter=6..3"), [6, 5, 4]);
+        assert!("diameter=3..3".parse::<Sweep>().is_err());
+    }
+
+    #[test]
+    fn descending() {
+        assert_eq!(
+            values("start=10..=-2"),
+            [10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, -1, -2]
+        );
+
+        let mut sweep = Sweep::new("start", 10, 0);
+        sweep.step = 4;
+        assert_eq!(sweep.values().collect::<Vec<_>>(), [10, 6, 2]);
+    }
+
+    #[test]
+    fn step() {
+        let mut sweep = Sweep::new("diameter", 0, 10);
+        sweep.step = 5;
+        assert_eq!(sweep.values().collect::<Vec<_>>(), [0, 5, 10]);
+
+        sweep.step = 0;
+        assert_eq!(sweep.values().count(), 11);
+    }
+
+    #[test]
+    fn invalid() {
+        for sweep in [
+            "diameter",
+            "diameter=10",
+            "diameter=a..=5",
+            "diameter=0..=5.5",
+        ] {
+            assert!(sweep.parse::<Sweep>().is_err(), "{}", sweep);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let sweep = "diameter=10..0".parse::<Sweep>().unwrap();
+        assert_eq!(sweep.to_string(), "diameter=10..=1");
+    }
+}
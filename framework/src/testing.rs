@@ -0,0 +1,215 @@
+//! Reference image tests.
+//!
+//! The functions in this module render an [`App`] without opening a window and compare the
+//! result against a stored PNG image. This makes it possible to turn interesting cases, which were
+//! found while using a debugger interactively, into regression tests:
+//!
+//! ```ignore
+//! #[test]
+//! fn polyline_joints() {
+//!     let mut app = PolylineDebug::new();
+//!     app.set_parameter("stroke=20").unwrap();
+//!     app.set_parameter("p3=190,60").unwrap();
+//!
+//!     framework::testing::assert_reference(&mut app, "tests/references/polyline_joints.png");
+//! }
+//! ```
+//!
+//! The `debug-tools` package contains this test in `tests/polyline.rs`.
+//!
+//! Missing reference images can be created by setting the `UPDATE_REFERENCES` environment
+//! variable to `1`, which will overwrite all references with the current output.
+
+use std::{
+    env, fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use embedded_graphics::{pixelcolor::Rgb888, prelude::*};
+use embedded_graphics_simulator::SimulatorDisplay;
+use image::{Rgb, RgbImage};
+
+use crate::{App, AppExt};
+
+/// Renders an app and compares the output against a reference image.
+///
+/// If the images don't match an image that highlights all differing pixels in red is written
+/// next to the reference image, using the `.diff.png` extension, and the actual output is saved
+/// with the `.actual.png` extension.
+pub fn check_reference<T, P>(app: &mut T, reference: P) -> Result<(), Mismatch>
+where
+    T: App,
+    P: AsRef<Path>,
+{
+    let reference = reference.as_ref();
+    let actual = to_image(&app.render(false));
+
+    if env::var_os("UPDATE_REFERENCES").is_some_and(|value| value == "1") {
+        if let Some(parent) = reference.parent() {
+            fs::create_dir_all(parent).unwrap_or_else(|e| {
+                panic!("couldn't create directory \"{}\": {}", parent.display(), e)
+            });
+        }
+
+        actual
+            .save(reference)
+            .unwrap_or_else(|e| panic!("couldn't write \"{}\": {}", reference.display(), e));
+
+        return Ok(());
+    }
+
+    let expected = match image::open(reference) {
+        Ok(image) => image.into_rgb8(),
+        Err(e) => {
+            return Err(Mismatch {
+                reference: reference.to_path_buf(),
+                kind: MismatchKind::MissingReference(e.to_string()),
+            })
+        }
+    };
+
+    if expected.dimensions() != actual.dimensions() {
+        return Err(Mismatch {
+            reference: reference.to_path_buf(),
+            kind: MismatchKind::Size {
+                expected: expected.dimensions(),
+                actual: actual.dimensions(),
+            },
+        });
+    }
+
+    let (diff, differing_pixels) = diff_image(&expected, &actual);
+    if differing_pixels == 0 {
+        return Ok(());
+    }
+
+    let diff_path = reference.with_extension("diff.png");
+    let actual_path = reference.with_extension("actual.png");
+
+    diff.save(&diff_path)
+        .unwrap_or_else(|e| panic!("couldn't write \"{}\": {}", diff_path.display(), e));
+    actual
+        .save(&actual_path)
+        .unwrap_or_else(|e| panic!("couldn't write \"{}\": {}", actual_path.display(), e));
+
+    Err(Mismatch {
+        reference: reference.to_path_buf(),
+        kind: MismatchKind::Pixels {
+            differing_pixels,
+            diff: diff_path,
+        },
+    })
+}
+
+/// Renders an app and asserts that the output matches a reference image.
+///
+/// See [`check_reference`] for more details.
+///
+/// # Panics
+///
+/// Panics if the output doesn't match the reference image.
+#[track_caller]
+pub fn assert_reference<T, P>(app: &mut T, reference: P)
+where
+    T: App,
+    P: AsRef<Path>,
+{
+    if let Err(mismatch) = check_reference(app, reference) {
+        panic!("{}", mismatch);
+    }
+}
+
+/// Reference image mismatch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    /// Path of the reference image.
+    pub reference: PathBuf,
+    /// Mismatch kind.
+    pub kind: MismatchKind,
+}
+
+/// Reference image mismatch kind.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MismatchKind {
+    /// The reference image couldn't be loaded.
+    MissingReference(String),
+    /// The reference image has a different size.
+    Size {
+        /// Size of the reference image.
+        expected: (u32, u32),
+        /// Size of the rendered image.
+        actual: (u32, u32),
+    },
+    /// Some pixels differ from the reference image.
+    Pixels {
+        /// Number of differing pixels.
+        differing_pixels: usize,
+        /// Path of the diff image.
+        diff: PathBuf,
+    },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reference = self.reference.display();
+
+        match &self.kind {
+            MismatchKind::MissingReference(error) => write!(
+                f,
+                "couldn't load reference image \"{}\": {} (set UPDATE_REFERENCES=1 to create it)",
+                reference, error
+            ),
+            MismatchKind::Size { expected, actual } => write!(
+                f,
+                "size mismatch for reference image \"{}\": expected {}x{}, got {}x{}",
+                reference, expected.0, expected.1, actual.0, actual.1
+            ),
+            MismatchKind::Pixels {
+                differing_pixels,
+                diff,
+            } => write!(
+                f,
+                "{} pixels differ from reference image \"{}\" (see \"{}\")",
+                differing_pixels,
+                reference,
+                diff.display()
+            ),
+        }
+    }
+}
+
+/// Converts a display into an image with one image pixel per display pixel.
+pub(crate) fn to_image<C>(display: &SimulatorDisplay<C>) -> RgbImage
+where
+    C: PixelColor + Into<Rgb888>,
+{
+    let size = display.size();
+
+    RgbImage::from_fn(size.width, size.height, |x, y| {
+        let color: Rgb888 = display.get_pixel(Point::new(x as i32, y as i32)).into();
+
+        Rgb([color.r(), color.g(), color.b()])
+    })
+}
+
+/// Creates an image which highlights the differences between two images.
+///
+/// Identical pixels are drawn dimmed and differing pixels are drawn in red. Returns the image
+/// and the number of differing pixels.
+fn diff_image(expected: &RgbImage, actual: &RgbImage) -> (RgbImage, usize) {
+    let mut differing_pixels = 0;
+
+    let diff = RgbImage::from_fn(expected.width(), expected.height(), |x, y| {
+        let expected = expected.get_pixel(x, y);
+        let actual = actual.get_pixel(x, y);
+
+        if expected == actual {
+            Rgb(actual.0.map(|c| c / 4))
+        } else {
+            differing_pixels += 1;
+            Rgb([255, 0, 0])
+        }
+    });
+
+    (diff, differing_pixels)
+}
@@ -0,0 +1,292 @@
+use embedded_graphics::{
+    geometry::AnchorPoint,
+    mono_font::{iso_8859_1::FONT_6X10, MonoTextStyle},
+    pixelcolor::Rgb888,
+    prelude::*,
+    primitives::{Line, PrimitiveStyle, Rectangle},
+    text::{Alignment, Baseline, Text, TextStyleBuilder},
+};
+use embedded_graphics_simulator::{
+    sdl2::{Keycode, MouseButton},
+    SimulatorDisplay, SimulatorEvent,
+};
+
+use crate::geometry::{Outline, OutlineKind};
+
+const MAX_ZOOM: u32 = 32;
+
+/// Minimum zoom level at which the grid is drawn.
+const MIN_GRID_ZOOM: u32 = 4;
+
+/// Zoomable and pannable view of the app.
+///
+/// The app is drawn into a scene display, which only covers the visible part of the logical
+/// display, and the scene is scaled up to fill the output display.
+pub(crate) struct Viewport {
+    size: Size,
+    zoom: u32,
+    offset: Point,
+    mouse_position: Point,
+    drag_start: Option<(Point, Point)>,
+    grid: Grid,
+}
+
+/// Grid overlay mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Grid {
+    /// No grid.
+    Off,
+    /// Lines between pixels.
+    Lines,
+    /// Lines between pixels and markers at the pixel centers.
+    LinesAndCenters,
+}
+
+impl Viewport {
+    /// Creates a new viewport for an output display with the given size.
+    pub(crate) fn new(size: Size) -> Self {
+        Self {
+            size,
+            zoom: 1,
+            offset: Point::zero(),
+            mouse_position: Point::zero(),
+            drag_start: None,
+            grid: Grid::Off,
+        }
+    }
+
+    /// Returns the visible area in logical coordinates.
+    pub(crate) fn visible_area(&self) -> Rectangle {
+        let size = Size::new(
+            self.size.width.div_ceil(self.zoom),
+            self.size.height.div_ceil(self.zoom),
+        );
+
+        Rectangle::new(self.offset, size)
+    }
+
+    /// Converts a point in output display coordinates into logical coordinates.
+    pub(crate) fn to_logical(&self, point: Point) -> Point {
+        let zoom = self.zoom as i32;
+
+        self.offset + Point::new(point.x.div_euclid(zoom), point.y.div_euclid(zoom))
+    }
+
+    /// Handles an event.
+    ///
+    /// `+` and `-` zoom in and out around the mouse cursor and `0` resets the viewport. The view
+    /// can be panned by dragging with the right mouse button and `G` cycles through the grid
+    /// modes. Returns `true` if the event was used by the viewport.
+    pub(crate) fn handle_event(&mut self, event: &SimulatorEvent) -> bool {
+        match *event {
+            SimulatorEvent::KeyDown { keycode, .. } => match keycode {
+                Keycode::Plus | Keycode::Equals | Keycode::KpPlus => {
+                    self.set_zoom((self.zoom * 2).min(MAX_ZOOM))
+                }
+                Keycode::Minus | Keycode::KpMinus => self.set_zoom((self.zoom / 2).max(1)),
+                Keycode::Num0 | Keycode::Kp0 => {
+                    self.zoom = 1;
+                    self.offset = Point::zero();
+                }
+                Keycode::G => {
+                    self.grid = match self.grid {
+                        Grid::Off => Grid::Lines,
+                        Grid::Lines => Grid::LinesAndCenters,
+                        Grid::LinesAndCenters => Grid::Off,
+                    }
+                }
+                _ => return false,
+            },
+            SimulatorEvent::MouseButtonDown {
+                mouse_btn: MouseButton::Right,
+                point,
+            } => self.drag_start = Some((point, self.offset)),
+            SimulatorEvent::MouseButtonUp {
+                mouse_btn: MouseButton::Right,
+                ..
+            } => self.drag_start = None,
+            SimulatorEvent::MouseMove { point } => {
+                self.mouse_position = point;
+
+                if let Some((start_point, start_offset)) = self.drag_start {
+                    self.offset = start_offset - (point - start_point) / self.zoom as i32;
+                    return true;
+                }
+
+                return false;
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    /// Changes the zoom level and keeps the logical point under the mouse cursor fixed.
+    fn set_zoom(&mut self, zoom: u32) {
+        let mouse_position = self.to_logical(self.mouse_position);
+
+        self.zoom = zoom;
+        self.offset = mouse_position - self.mouse_position / zoom as i32;
+    }
+
+    /// Converts the positions in mouse events into logical coordinates.
+    pub(crate) fn map_event(&self, event: SimulatorEvent) -> SimulatorEvent {
+        match event {
+            SimulatorEvent::MouseMove { point } => SimulatorEvent::MouseMove {
+                point: self.to_logical(point),
+            },
+            SimulatorEvent::MouseButtonDown { mouse_btn, point } => {
+                SimulatorEvent::MouseButtonDown {
+                    mouse_btn,
+                    point: self.to_logical(point),
+                }
+            }
+            SimulatorEvent::MouseButtonUp { mouse_btn, point } => SimulatorEvent::MouseButtonUp {
+                mouse_btn,
+                point: self.to_logical(point),
+            },
+            event => event,
+        }
+    }
+
+    /// Draws the scaled scene to the output display.
+    pub(crate) fn draw_scene<C: PixelColor>(
+        &self,
+        scene: &SimulatorDisplay<C>,
+        target: &mut SimulatorDisplay<C>,
+    ) {
+        let zoom = self.zoom as i32;
+        let area = Rectangle::new(Point::zero(), self.size);
+
+        target
+            .fill_contiguous(
+                &area,
+                area.points()
+                    .map(|p| scene.get_pixel(Point::new(p.x / zoom, p.y / zoom))),
+            )
+            .unwrap();
+    }
+
+    /// Draws the grid overlay.
+    ///
+    /// The grid is only drawn if the zoom level is high enough to keep the scene visible.
+    pub(crate) fn draw_grid<C>(&self, target: &mut SimulatorDisplay<C>)
+    where
+        C: PixelColor + From<Rgb888>,
+    {
+        if self.grid == Grid::Off || self.zoom < MIN_GRID_ZOOM {
+            return;
+        }
+
+        let zoom = self.zoom as i32;
+        let area = Rectangle::new(Point::zero(), self.size);
+
+        let line_color = C::from(Rgb888::new(0x40, 0x40, 0x40));
+        let lines = area
+            .points()
+            .filter(|p| p.x % zoom == 0 || p.y % zoom == 0)
+            .map(|p| Pixel(p, line_color));
+        target.draw_iter(lines).unwrap();
+
+        if self.grid == Grid::LinesAndCenters {
+            let center_color = C::from(Rgb888::new(0x80, 0x80, 0x80));
+            let centers = area
+                .points()
+                .filter(|p| p.x % zoom == zoom / 2 && p.y % zoom == zoom / 2)
+                .map(|p| Pixel(p, center_color));
+            target.draw_iter(centers).unwrap();
+        }
+    }
+
+    /// Draws the outlines of areas, which are given in logical coordinates.
+    pub(crate) fn draw_areas<C: PixelColor>(
+        &self,
+        areas: &[Rectangle],
+        color: C,
+        target: &mut SimulatorDisplay<C>,
+    ) {
+        let style = PrimitiveStyle::with_stroke(color, 1);
+
+        for area in areas {
+            Rectangle::new(
+                (area.top_left - self.offset) * self.zoom as i32,
+                area.size * self.zoom,
+            )
+            .into_styled(style)
+            .draw(target)
+            .unwrap();
+        }
+    }
+
+    /// Converts a point in continuous logical coordinates into output display coordinates.
+    pub(crate) fn to_output(&self, point: (f32, f32)) -> Point {
+        let zoom = self.zoom as f32;
+
+        Point::new(
+            ((point.0 - self.offset.x as f32) * zoom).floor() as i32,
+            ((point.1 - self.offset.y as f32) * zoom).floor() as i32,
+        )
+    }
+
+    /// Draws ideal outlines.
+    pub(crate) fn draw_outlines<C>(&self, outlines: &[Outline], target: &mut SimulatorDisplay<C>)
+    where
+        C: PixelColor + From<Rgb888>,
+    {
+        for outline in outlines {
+            let color = match outline.kind {
+                OutlineKind::Shape => Rgb888::CSS_MAGENTA,
+                OutlineKind::StrokeEdge => Rgb888::CSS_CYAN,
+            };
+            let style = PrimitiveStyle::with_stroke(C::from(color), 1);
+
+            let points = outline.shape.to_polyline(2.0 / self.zoom as f32);
+            for segment in points.windows(2) {
+                Line::new(self.to_output(segment[0]), self.to_output(segment[1]))
+                    .into_styled(style)
+                    .draw(target)
+                    .unwrap();
+            }
+        }
+    }
+
+    /// Draws the zoom level and the offset if the viewport isn't in its default state.
+    pub(crate) fn draw_status<T>(
+        &self,
+        target: &mut T,
+        color: T::Color,
+        background_color: T::Color,
+    ) -> Result<(), T::Error>
+    where
+        T: DrawTarget,
+    {
+        if self.zoom == 1 && self.offset == Point::zero() {
+            return Ok(());
+        }
+
+        let text = format!("{}x ({}, {})", self.zoom, self.offset.x, self.offset.y);
+
+        let position = target.bounding_box().anchor_point(AnchorPoint::TopRight);
+        let background = Rectangle::with_corners(
+            position - Point::new(text.len() as i32 * 6 + 3, 0),
+            position + Point::new(0, 10),
+        );
+
+        background
+            .into_styled(PrimitiveStyle::with_fill(background_color))
+            .draw(target)?;
+
+        Text::with_text_style(
+            &text,
+            position + Point::new(-2, 1),
+            MonoTextStyle::new(&FONT_6X10, color),
+            TextStyleBuilder::new()
+                .alignment(Alignment::Right)
+                .baseline(Baseline::Top)
+                .build(),
+        )
+        .draw(target)?;
+
+        Ok(())
+    }
+}